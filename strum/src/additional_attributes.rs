@@ -50,6 +50,133 @@
 //! - You can also apply the `#[strum(ascii_case_insensitive)]` attribute to the enum,
 //!   and this has the same effect of applying it to every variant.
 //!
+//! - `#[strum(prefix = "...")]` and `#[strum(suffix = "...")]` add a prefix or a suffix to the
+//!   string produced for every variant by `Display`, `AsRefStr`, `IntoStaticStr` and `VariantNames`.
+//!
+//! - `#[strum(strip_prefix = "...")]` and `#[strum(strip_suffix = "...")]` remove a prefix or a suffix
+//!   from the identifier of every variant before it is converted using `serialize_all`. Variants whose
+//!   identifier doesn't start (or end) with the given value are left untouched, as are variants that
+//!   have a `serialize` or `to_string` attribute. It is a compile error if stripping leaves a variant
+//!   with an empty name or gives it the name of another variant, including names set with `serialize`
+//!   or `to_string`.
+//!
+//!   ```rust
+//!   use gratte;
+//!
+//!   #[derive(Debug, Eq, PartialEq, gratte::Display, gratte::EnumString)]
+//!   #[strum(strip_prefix = "Key", serialize_all = "snake_case")]
+//!   enum KeyCode {
+//!       KeyEscape,
+//!       KeyPageUp,
+//!       Enter,
+//!   }
+//!
+//!   assert_eq!("escape", KeyCode::KeyEscape.to_string());
+//!   assert_eq!("page_up", KeyCode::KeyPageUp.to_string());
+//!   assert_eq!("enter", KeyCode::Enter.to_string());
+//!   assert_eq!(Ok(KeyCode::KeyPageUp), "page_up".parse());
+//!   ```
+//!
+//!   ```compile_fail
+//!   // `KeyEscape` and `Escape` would both be named `Escape`
+//!   #[derive(gratte::Display)]
+//!   #[strum(strip_prefix = "Key")]
+//!   enum KeyCode {
+//!       KeyEscape,
+//!       Escape,
+//!   }
+//!   ```
+//!
+//!   ```compile_fail
+//!   // `KeyTab` would be named `Tab`, like `Tabulation`
+//!   #[derive(gratte::Display)]
+//!   #[strum(strip_prefix = "Key")]
+//!   enum KeyCode {
+//!       KeyTab,
+//!       #[strum(serialize = "Tab")]
+//!       Tabulation,
+//!   }
+//!   ```
+//!
+//! - `#[strum(verify_roundtrip)]` makes `EnumString` check that the string produced by `Display`
+//!   for every unit variant is parsed back into the same variant. Variants skipped for parsing or
+//!   display are ignored. Any mismatch, for example because of a `prefix` that `EnumString` doesn't
+//!   expect or a `serialize` value shared by two variants, is a compile error. All strings involved
+//!   must be literals.
+//!
+//!   ```rust
+//!   use gratte;
+//!
+//!   #[derive(Debug, Eq, PartialEq, gratte::Display, gratte::EnumString)]
+//!   #[strum(verify_roundtrip, serialize_all = "kebab-case")]
+//!   enum Shape {
+//!       #[strum(to_string = "circle", serialize = "round")]
+//!       Circle,
+//!       SquareBox,
+//!   }
+//!
+//!   assert_eq!(Ok(Shape::SquareBox), Shape::SquareBox.to_string().parse());
+//!   ```
+//!
+//!   ```compile_fail
+//!   // `Display` produces "shape_circle", which `EnumString` doesn't recognize
+//!   #[derive(gratte::Display, gratte::EnumString)]
+//!   #[strum(verify_roundtrip, prefix = "shape_")]
+//!   enum Shape {
+//!       Circle,
+//!   }
+//!   ```
+//!
+//! - `#[strum(table_disabled = "compile_error")]` makes `EnumTable` generate a `YourEnumTableKey`
+//!   enum with only the variants that aren't disabled, and key the table with it instead of your
+//!   enum, so that indexing the table with a disabled variant doesn't compile rather than panicking.
+//!   `YourEnumTableKey` converts into your enum with `From`, and back with `TryFrom`. The default,
+//!   `"panic"`, keys the table with your enum. In both cases, `get`, `get_mut`, `replace` and
+//!   `try_set` accept any variant of your enum and report disabled variants instead of panicking.
+//!
+//!   ```rust
+//!   use gratte::EnumTable;
+//!
+//!   #[derive(Debug, PartialEq, EnumTable)]
+//!   #[strum(table_disabled = "compile_error")]
+//!   enum Direction {
+//!       North,
+//!       #[strum(disabled)]
+//!       Up,
+//!       South,
+//!   }
+//!
+//!   let mut map = DirectionTable::filled(0);
+//!   map[DirectionTableKey::North] = 1;
+//!   assert_eq!(map.get(Direction::North), Some(&1));
+//!   assert_eq!(map.get(Direction::Up), None);
+//!   assert_eq!(DirectionTableKey::try_from(Direction::Up), Err(Direction::Up));
+//!   ```
+//!
+//!   ```compile_fail
+//!   use gratte::EnumTable;
+//!
+//!   #[derive(EnumTable)]
+//!   #[strum(table_disabled = "compile_error")]
+//!   enum Direction {
+//!       North,
+//!       #[strum(disabled)]
+//!       Up,
+//!   }
+//!
+//!   let map = DirectionTable::filled(0);
+//!   let _ = map[Direction::Up];
+//!   ```
+//!
+//! - `#[strum(table_serde)]`: With the `serde` feature, makes the table generated by `EnumTable`
+//!   implement `Serialize` and `Deserialize`, as a map from the names of the variants, as produced
+//!   by `Display`, to their values. The names must be known when the macro runs. Tables without this
+//!   attribute don't implement the traits, so enabling the feature never changes what they require.
+//!   Deserializing a table fails if the name of a variant that isn't disabled is missing.
+//!
+//! - `#[strum(table_serde_default)]`: Like `table_serde`, but missing values are set to
+//!   `Default::default()` when deserializing a table.
+//!
 //! ## Attributes on Variants
 //!
 //! Custom attributes are applied to a variant by adding `#[strum(parameter="value")]` to the variant.
@@ -74,13 +201,83 @@
 //!   The plugin will fail if the data doesn't implement From<&str>. You can only have one `default`
 //!   on your enum.
 //!
+//! - `catch_all`: Applied to a single variant with a single field, which holds the values that
+//!   don't match any other variant. `FromRepr` returns the variant with the unknown discriminant,
+//!   so the field must have the type of the discriminant, and `to_repr` returns the stored value.
+//!   `TryFrom` is then replaced by an infallible `From` of the discriminant type. `EnumString`
+//!   parses unknown strings into the field with `FromStr` and fails if that fails too; it can't be
+//!   combined with `default`. The variant stands for every unknown discriminant, so it is left out
+//!   of the variants listed, counted and indexed by `EnumIter`, `VariantArray`, `EnumCount`,
+//!   `EnumIndex`, `EnumArray`, `EnumSet` and `EnumCycle`, like a variant skipped for `iter`. A
+//!   stored value equal to the discriminant of another variant, like `Unknown(2)` below, is
+//!   returned as is by `to_repr`, so `from_repr` gives back the other variant.
+//!
+//!   ```rust
+//!   use gratte::{EnumCount, EnumIter, EnumString, FromRepr, IntoEnumIterator};
+//!
+//!   #[derive(Debug, PartialEq, FromRepr, EnumString, EnumIter, EnumCount)]
+//!   #[repr(u8)]
+//!   enum Opcode {
+//!       Ping = 1,
+//!       Pong = 2,
+//!       #[strum(catch_all)]
+//!       Unknown(u8),
+//!   }
+//!
+//!   assert_eq!(Opcode::from(2), Opcode::Pong);
+//!   assert_eq!(Opcode::from(7), Opcode::Unknown(7));
+//!   assert_eq!(Opcode::Unknown(7).to_repr(), 7);
+//!   assert_eq!(Opcode::from(Opcode::Unknown(2).to_repr()), Opcode::Pong);
+//!   assert_eq!("7".parse(), Ok(Opcode::Unknown(7)));
+//!   assert_eq!(Opcode::iter().count(), 2);
+//!   assert_eq!(Opcode::COUNT, 2);
+//!   ```
+//!
 //! - `transparent`: Signals that the inner field's implementation should be used, instead of generating
 //!   one for this variant. Only applicable to enum variants with a single field. Compatible with the
 //!   `AsRefStr`, `Display` and `IntoStaticStr` derive macros. Note that `IntoStaticStr` has a few restrictions,
 //!   the value must be `'static` and `const_into_str` is not supported in combination with `transparent` b/c
 //!   transparent relies on a call on `From::from(variant)`.
 //!
-//! - `disabled`: removes variant from generated code.
+//! - `disabled`: removes variant from generated code. This is the same as skipping the variant for
+//!   every target listed below.
+//!
+//! - `skip(...)`: removes variant from the code generated for some features only. The following
+//!   targets are supported:
+//!
+//!   | Target     | Macros                                                                               | Effect on the variant                                                                                    |
+//!   |------------|--------------------------------------------------------------------------------------|----------------------------------------------------------------------------------------------------------|
+//!   | `parse`    | `EnumString`                                                                         | Never returned when parsing a string                                                                     |
+//!   | `display`  | `Display`, `AsRefStr`, `IntoStaticStr`                                               | Panics when converted to a string                                                                        |
+//!   | `names`    | `VariantNames`, `VariantMetadata`, `EnumIter`                                        | Not listed in `VARIANTS` or `VARIANT_NAMES`; named `None` by `iter_with_names`                           |
+//!   | `iter`     | `EnumIter`, `VariantArray`, `EnumCount`, `VariantIterator`, `EnumCycle`, `EnumIndex` | Not iterated, not listed in `VARIANTS` and not counted; never returned by `next` or `prev`; has no index |
+//!   | `messages` | `EnumMessage`                                                                        | Has no message, detailed message or documentation                                                        |
+//!   | `props`    | `EnumProperty`                                                                       | Has no properties                                                                                        |
+//!   | `repr`     | `FromRepr`                                                                           | Never returned by `from_repr`                                                                            |
+//!   | `table`    | `EnumTable`                                                                          | Has no slot in the table; indexing with it panics                                                        |
+//!   | `is`       | `EnumIs`                                                                             | Has no `is_*` method                                                                                     |
+//!   | `try_as`   | `EnumTryAs`                                                                          | Has no `try_as_*` methods                                                                                |
+//!
+//!   `EnumDiscriminants` always includes every variant. `EnumMessage::get_serializations` is also
+//!   never affected. For example, a deprecated variant can still be parsed and displayed while
+//!   being hidden from listings:
+//!
+//!   ```rust
+//!   use gratte::{EnumIter, EnumString, IntoEnumIterator, VariantNames};
+//!
+//!   #[derive(Debug, PartialEq, EnumString, gratte::Display, EnumIter, VariantNames)]
+//!   enum Level {
+//!       Low,
+//!       High,
+//!       #[strum(skip(iter, names))]
+//!       Medium,
+//!   }
+//!
+//!   assert_eq!(Ok(Level::Medium), "Medium".parse());
+//!   assert_eq!("Medium", Level::Medium.to_string());
+//!   assert_eq!(vec![Level::Low, Level::High], Level::iter().collect::<Vec<_>>());
+//!   assert_eq!(["Low", "High"], Level::VARIANTS);
+//!   ```
 //!
 //! - `ascii_case_insensitive`: makes the comparison to this variant case-insensitive (ASCII only).
 //!   If the whole enum is marked `ascii_case_insensitive`, you can specify `ascii_case_insensitive = false`
@@ -95,4 +292,86 @@
 //!
 //! - Structured documentation, as in `/// ...`: If using `EnumMessage`, is accessible via get_documentation().
 //!
+//! - `iter_fields`: makes `EnumIter` yield the variant once for every combination of its field values,
+//!   instead of once with every field set to `Default::default()`. Fields of type `bool` yield `false`
+//!   then `true`, and fields of any other type must implement `IntoEnumIterator`, unless they are marked
+//!   with `#[strum(iter_values = [...])]` to list the values explicitly. The last field varies the fastest.
+//!   Applying `#[strum(iter_fields)]` to the enum has the same effect as applying it to every variant.
+//!   Note that `EnumCount` still counts variants, not values.
+//!
+//!   ```rust
+//!   use gratte::{EnumIter, IntoEnumIterator};
+//!
+//!   #[derive(Debug, PartialEq, EnumIter)]
+//!   enum Color {
+//!       Red,
+//!       Blue,
+//!   }
+//!
+//!   #[derive(Debug, PartialEq, EnumIter)]
+//!   enum Shape {
+//!       #[strum(iter_fields)]
+//!       Circle(Color, bool),
+//!       #[strum(iter_fields)]
+//!       Line { #[strum(iter_values = [1, 2])] width: u32 },
+//!   }
+//!
+//!   assert_eq!(
+//!       vec![
+//!           Shape::Circle(Color::Red, false),
+//!           Shape::Circle(Color::Red, true),
+//!           Shape::Circle(Color::Blue, false),
+//!           Shape::Circle(Color::Blue, true),
+//!           Shape::Line { width: 1 },
+//!           Shape::Line { width: 2 },
+//!       ],
+//!       Shape::iter().collect::<Vec<_>>(),
+//!   );
+//!   ```
+//!
+//! - `weight = N`: With the `rand` feature, `Distribution<YourEnum>` is implemented for
+//!   `rand::distr::StandardUniform` by `VariantArray`, which also provides `random()`, or by `EnumIter`
+//!   if some of the variants it yields have fields. Only one of them can implement it: `EnumIter` leaves
+//!   enums of unit variants to `VariantArray`. Variants are picked uniformly among those that aren't
+//!   skipped. This attribute makes the variant `N` times as likely to be picked as a variant without it;
+//!   a weight of `0` means it is never picked, but at least one variant must have a non-zero weight.
+//!   Fields are set to `Default::default()`, or picked uniformly among the values yielded by `EnumIter`
+//!   if the variant uses `iter_fields`. Variants with `iter_fields` that yield no values are never
+//!   picked.
+//!
 //! - `props(key="value")`: Enables associating additional information with a given variant.
+//!
+//! ## Non-literal values
+//!
+//! The `serialize`, `to_string`, `message` and `detailed_message` variant attributes, as well as the
+//! `prefix` and `suffix` enum attributes, also accept a path to a `&'static str` constant or a macro
+//! invocation like `concat!(...)` or `stringify!(...)` instead of a string literal. The value is then
+//! emitted as-is in the generated code. Some features need to know the value when the macro runs,
+//! however, and will report a compile error if it isn't a string literal:
+//!
+//! - `use_phf` requires literal `serialize` and `to_string` values.
+//! - When a variant has multiple `serialize` values and no `to_string`, they must all be literals so that
+//!   the longest one can be picked.
+//! - `to_string` values using interpolation cannot be combined with a non-literal `prefix` or `suffix`.
+//!
+//! ```rust
+//! use gratte;
+//!
+//! pub const API_PREFIX: &str = "v2_";
+//! const GREEN: &str = "green";
+//!
+//! #[derive(Debug, Eq, PartialEq, gratte::Display, gratte::EnumString)]
+//! #[strum(prefix = API_PREFIX)]
+//! enum Color {
+//!     #[strum(serialize = GREEN)]
+//!     Green,
+//!     #[strum(serialize = concat!("bl", "ue"))]
+//!     Blue,
+//!     Red,
+//! }
+//!
+//! assert_eq!("v2_green", Color::Green.to_string());
+//! assert_eq!("v2_blue", Color::Blue.to_string());
+//! assert_eq!("v2_Red", Color::Red.to_string());
+//! assert_eq!(Ok(Color::Green), "green".parse());
+//! ```
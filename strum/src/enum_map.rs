@@ -0,0 +1,454 @@
+//! A map from the variants of an enum to values, backed by an array.
+//!
+//! [`EnumMap<K, V>`] stores a value of type `V` for each variant of `K`, where `K` implements
+//! [`EnumArray<V>`]. Unlike the tables generated by `EnumTable`, a single generic type is used
+//! for every enum, so functions can take maps over any enum:
+//!
+//! ```rust
+//! use gratte::{EnumArray, EnumIndex, EnumMap};
+//!
+//! #[derive(Debug, Clone, Copy, EnumIndex, EnumArray)]
+//! enum Color {
+//!     Red,
+//!     Green,
+//!     Blue,
+//! }
+//!
+//! fn total<K: EnumArray<u32>>(map: &EnumMap<K, u32>) -> u32 {
+//!     map.values().sum()
+//! }
+//!
+//! let mut map = EnumMap::from_closure(|color| match color {
+//!     Color::Red => 1,
+//!     _ => 2,
+//! });
+//! map[Color::Blue] += 10;
+//! assert_eq!(total(&map), 15);
+//! assert_eq!(map.as_slice(), &[1, 2, 12]);
+//! ```
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut, Range};
+use core::slice;
+
+use crate::{EnumArray, EnumIndex};
+
+/// A map from the variants of `K` to values of type `V`. See the [module documentation](self).
+///
+/// Values are stored in an array, in the order of the indices given by [`EnumIndex`], which is
+/// the declaration order for derived implementations. Variants without an index, such as those
+/// skipped for `iter`, have no entry: indexing the map with them panics, while [`get`](Self::get)
+/// and [`get_mut`](Self::get_mut) return `None`.
+pub struct EnumMap<K: EnumArray<V>, V> {
+    values: K::Array,
+}
+
+// Returns the key with the given index, which must be lower than `K::COUNT`.
+fn key<K: EnumIndex>(index: usize) -> K {
+    K::from_index(index).expect("EnumIndex::from_index returned None for an index below COUNT")
+}
+
+impl<K: EnumArray<V>, V> EnumMap<K, V> {
+    /// Creates a map from an array of values, in the order of the indices of the keys.
+    #[inline]
+    pub const fn from_array(values: K::Array) -> Self {
+        EnumMap { values }
+    }
+
+    /// Creates a map by calling `func` with each key, in the order of their indices.
+    #[inline]
+    pub fn from_closure<F: FnMut(K) -> V>(mut func: F) -> Self {
+        EnumMap {
+            values: K::array_from_fn(|index| func(key(index))),
+        }
+    }
+
+    /// Creates a map with `value` for every key.
+    #[inline]
+    pub fn filled(value: V) -> Self
+    where
+        V: Clone,
+    {
+        EnumMap {
+            values: K::array_from_fn(|_| value.clone()),
+        }
+    }
+
+    /// Creates a new map by running a function on each key and the corresponding value.
+    #[inline]
+    pub fn transform<U, F: FnMut(K, &V) -> U>(&self, mut func: F) -> EnumMap<K, U>
+    where
+        K: EnumArray<U>,
+    {
+        let values = self.as_slice();
+        EnumMap {
+            values: <K as EnumArray<U>>::array_from_fn(|index| func(key(index), &values[index])),
+        }
+    }
+
+    /// Returns the array of values, in the order of the indices of the keys.
+    #[inline]
+    pub fn into_array(self) -> K::Array {
+        self.values
+    }
+
+    /// Returns the values as a slice, in the order of the indices of the keys.
+    #[inline]
+    pub fn as_slice(&self) -> &[V] {
+        self.values.as_ref()
+    }
+
+    /// Returns the values as a mutable slice, in the order of the indices of the keys.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [V] {
+        self.values.as_mut()
+    }
+
+    /// Returns a reference to the value of `key`, or `None` if `key` has no index.
+    #[inline]
+    pub fn get(&self, key: K) -> Option<&V> {
+        key.try_index().map(|index| &self.as_slice()[index])
+    }
+
+    /// Returns a mutable reference to the value of `key`, or `None` if `key` has no index.
+    #[inline]
+    pub fn get_mut(&mut self, key: K) -> Option<&mut V> {
+        key.try_index()
+            .map(move |index| &mut self.as_mut_slice()[index])
+    }
+
+    /// Returns the number of entries in the map, which is the number of indexed variants of `K`.
+    #[inline]
+    pub fn len(&self) -> usize {
+        K::COUNT
+    }
+
+    /// Returns whether `K` has no indexed variants.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        K::COUNT == 0
+    }
+
+    /// Returns an iterator over the keys and references to their values.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            values: self.as_slice().iter().enumerate(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the keys and mutable references to their values.
+    #[inline]
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            values: self.as_mut_slice().iter_mut().enumerate(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the keys, in the order of their indices.
+    #[inline]
+    pub fn keys(&self) -> Keys<K> {
+        Keys {
+            indices: 0..K::COUNT,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over references to the values.
+    #[inline]
+    pub fn values(&self) -> slice::Iter<'_, V> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator over mutable references to the values.
+    #[inline]
+    pub fn values_mut(&mut self) -> slice::IterMut<'_, V> {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<K: EnumArray<Option<V>> + EnumArray<V>, V> EnumMap<K, Option<V>> {
+    /// Converts `EnumMap<K, Option<V>>` into `Option<EnumMap<K, V>>`. Returns `Some` if all values
+    /// are `Some`, otherwise returns `None`.
+    pub fn all(self) -> Option<EnumMap<K, V>> {
+        if self.values().any(Option::is_none) {
+            return None;
+        }
+        let mut values = self.values.into_iter();
+        Some(EnumMap {
+            values: <K as EnumArray<V>>::array_from_fn(|_| values.next().flatten().unwrap()),
+        })
+    }
+}
+
+impl<K: EnumArray<Result<V, E>> + EnumArray<V>, V, E> EnumMap<K, Result<V, E>> {
+    /// Converts `EnumMap<K, Result<V, E>>` into `Result<EnumMap<K, V>, E>`. Returns `Ok` if all
+    /// values are `Ok`, otherwise returns the first `Err`.
+    pub fn all_ok(self) -> Result<EnumMap<K, V>, E> {
+        if let Some(position) = self.values().position(Result::is_err) {
+            return Err(self
+                .values
+                .into_iter()
+                .nth(position)
+                .unwrap()
+                .err()
+                .unwrap());
+        }
+        let mut values = self.values.into_iter();
+        Ok(EnumMap {
+            values: <K as EnumArray<V>>::array_from_fn(|_| values.next().unwrap().ok().unwrap()),
+        })
+    }
+}
+
+/// # Panics
+///
+/// If the key has no index. Use [`EnumMap::get`] to handle these keys.
+impl<K: EnumArray<V>, V> Index<K> for EnumMap<K, V> {
+    type Output = V;
+
+    #[inline]
+    fn index(&self, key: K) -> &V {
+        &self.as_slice()[key.index()]
+    }
+}
+
+/// # Panics
+///
+/// If the key has no index. Use [`EnumMap::get_mut`] to handle these keys.
+impl<K: EnumArray<V>, V> IndexMut<K> for EnumMap<K, V> {
+    #[inline]
+    fn index_mut(&mut self, key: K) -> &mut V {
+        &mut self.as_mut_slice()[key.index()]
+    }
+}
+
+impl<K: EnumArray<V>, V: Default> Default for EnumMap<K, V> {
+    #[inline]
+    fn default() -> Self {
+        EnumMap {
+            values: K::array_from_fn(|_| V::default()),
+        }
+    }
+}
+
+impl<K: EnumArray<V>, V> Clone for EnumMap<K, V>
+where
+    K::Array: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        EnumMap {
+            values: self.values.clone(),
+        }
+    }
+}
+
+impl<K: EnumArray<V>, V> Copy for EnumMap<K, V> where K::Array: Copy {}
+
+impl<K: EnumArray<V>, V: PartialEq> PartialEq for EnumMap<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<K: EnumArray<V>, V: Eq> Eq for EnumMap<K, V> {}
+
+impl<K: EnumArray<V>, V: Hash> Hash for EnumMap<K, V> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<K: EnumArray<V> + fmt::Debug, V: fmt::Debug> fmt::Debug for EnumMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: EnumArray<V>, V: Default> FromIterator<(K, V)> for EnumMap<K, V> {
+    /// Creates a map from `(key, value)` pairs. Keys without a value are set to `V::default()`
+    /// and the last value is kept for keys with several values.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        for (key, value) in iter {
+            map[key] = value;
+        }
+        map
+    }
+}
+
+impl<K: EnumArray<V>, V> IntoIterator for EnumMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter {
+            values: self.values.into_iter().enumerate(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, K: EnumArray<V>, V> IntoIterator for &'a EnumMap<K, V> {
+    type Item = (K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K: EnumArray<V>, V> IntoIterator for &'a mut EnumMap<K, V> {
+    type Item = (K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    #[inline]
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over the keys of an [`EnumMap`] and references to their values.
+pub struct Iter<'a, K, V> {
+    values: core::iter::Enumerate<slice::Iter<'a, V>>,
+    marker: PhantomData<fn() -> K>,
+}
+
+impl<'a, K: EnumIndex, V> Iterator for Iter<'a, K, V> {
+    type Item = (K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next().map(|(index, value)| (key(index), value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<K: EnumIndex, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.values
+            .next_back()
+            .map(|(index, value)| (key(index), value))
+    }
+}
+
+impl<K: EnumIndex, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K: EnumIndex, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Iter {
+            values: self.values.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+/// An iterator over the keys of an [`EnumMap`] and mutable references to their values.
+pub struct IterMut<'a, K, V> {
+    values: core::iter::Enumerate<slice::IterMut<'a, V>>,
+    marker: PhantomData<fn() -> K>,
+}
+
+impl<'a, K: EnumIndex, V> Iterator for IterMut<'a, K, V> {
+    type Item = (K, &'a mut V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next().map(|(index, value)| (key(index), value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<K: EnumIndex, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.values
+            .next_back()
+            .map(|(index, value)| (key(index), value))
+    }
+}
+
+impl<K: EnumIndex, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K: EnumIndex, V> FusedIterator for IterMut<'_, K, V> {}
+
+/// An iterator over the keys of an [`EnumMap`] and their values.
+pub struct IntoIter<K: EnumArray<V>, V> {
+    values: core::iter::Enumerate<<K::Array as IntoIterator>::IntoIter>,
+    marker: PhantomData<fn() -> K>,
+}
+
+impl<K: EnumArray<V>, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next().map(|(index, value)| (key(index), value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+/// An iterator over the keys of an [`EnumMap`].
+pub struct Keys<K> {
+    indices: Range<usize>,
+    marker: PhantomData<fn() -> K>,
+}
+
+impl<K: EnumIndex> Iterator for Keys<K> {
+    type Item = K;
+
+    #[inline]
+    fn next(&mut self) -> Option<K> {
+        self.indices.next().map(key)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.indices.size_hint()
+    }
+}
+
+impl<K: EnumIndex> DoubleEndedIterator for Keys<K> {
+    #[inline]
+    fn next_back(&mut self) -> Option<K> {
+        self.indices.next_back().map(key)
+    }
+}
+
+impl<K: EnumIndex> ExactSizeIterator for Keys<K> {}
+
+impl<K: EnumIndex> FusedIterator for Keys<K> {}
+
+impl<K> Clone for Keys<K> {
+    fn clone(&self) -> Self {
+        Keys {
+            indices: self.indices.clone(),
+            marker: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! Iterators over the values of types implementing [`IntoEnumIterator`].
+//!
+//! `gratte` implements [`IntoEnumIterator`] for:
+//!
+//! - `bool`, which yields `false`, then `true`
+//! - `Option<T>`, which yields `None`, then `Some` of every value of `T`
+//! - `Result<T, E>`, which yields `Ok` of every value of `T`, then `Err` of every value of `E`
+//! - tuples of up to 4 elements, which yield every combination of the values of their elements
+//!   (the last element varying the fastest)
+//!
+//! Of these, only `bool` implements [`VariantArray`]: its `VARIANTS` has to be a `'static` slice,
+//! and the arrays of the values of `Option<T>`, `Result<T, E>` and tuples can't be built in a
+//! constant for a generic `T`, since their lengths depend on the number of values of `T`.
+//!
+//! Since `Option` and `Result` have inherent `iter` methods, the trait has to be named explicitly
+//! to iterate over their values, as in `<Option<T> as IntoEnumIterator>::iter()`.
+//!
+//! ```rust
+//! use gratte::IntoEnumIterator;
+//!
+//! let values: Vec<(bool, Option<bool>)> = <(bool, Option<bool>)>::iter().collect();
+//! assert_eq!(
+//!     vec![
+//!         (false, None),
+//!         (false, Some(false)),
+//!         (false, Some(true)),
+//!         (true, None),
+//!         (true, Some(false)),
+//!         (true, Some(true)),
+//!     ],
+//!     values,
+//! );
+//! ```
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::{EnumProperty, IntoEnumIterator, VariantArray};
+
+impl IntoEnumIterator for bool {
+    type Iterator = core::array::IntoIter<bool, 2>;
+
+    fn iter() -> Self::Iterator {
+        [false, true].into_iter()
+    }
+}
+
+impl VariantArray for bool {
+    const VARIANTS: &'static [Self] = &[false, true];
+}
+
+/// An iterator over the values of `Option<T>`. See the [module documentation](self).
+pub struct OptionIter<T: IntoEnumIterator> {
+    none: bool,
+    some: T::Iterator,
+}
+
+impl<T: IntoEnumIterator> IntoEnumIterator for Option<T> {
+    type Iterator = OptionIter<T>;
+
+    fn iter() -> Self::Iterator {
+        OptionIter {
+            none: true,
+            some: T::iter(),
+        }
+    }
+}
+
+impl<T: IntoEnumIterator> Iterator for OptionIter<T> {
+    type Item = Option<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.none {
+            self.none = false;
+            return Some(None);
+        }
+        self.some.next().map(Some)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: IntoEnumIterator> DoubleEndedIterator for OptionIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.some.next_back() {
+            Some(value) => Some(Some(value)),
+            None if self.none => {
+                self.none = false;
+                Some(None)
+            }
+            None => None,
+        }
+    }
+}
+
+impl<T: IntoEnumIterator> ExactSizeIterator for OptionIter<T> {
+    fn len(&self) -> usize {
+        usize::from(self.none) + self.some.len()
+    }
+}
+
+impl<T: IntoEnumIterator> FusedIterator for OptionIter<T> {}
+
+impl<T: IntoEnumIterator> Clone for OptionIter<T> {
+    fn clone(&self) -> Self {
+        OptionIter {
+            none: self.none,
+            some: self.some.clone(),
+        }
+    }
+}
+
+impl<T: IntoEnumIterator> fmt::Debug for OptionIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OptionIter")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// An iterator over the values of `Result<T, E>`. See the [module documentation](self).
+pub struct ResultIter<T: IntoEnumIterator, E: IntoEnumIterator> {
+    ok: T::Iterator,
+    err: E::Iterator,
+}
+
+impl<T: IntoEnumIterator, E: IntoEnumIterator> IntoEnumIterator for Result<T, E> {
+    type Iterator = ResultIter<T, E>;
+
+    fn iter() -> Self::Iterator {
+        ResultIter {
+            ok: T::iter(),
+            err: E::iter(),
+        }
+    }
+}
+
+impl<T: IntoEnumIterator, E: IntoEnumIterator> Iterator for ResultIter<T, E> {
+    type Item = Result<T, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.ok.next() {
+            Some(value) => Some(Ok(value)),
+            None => self.err.next().map(Err),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T: IntoEnumIterator, E: IntoEnumIterator> DoubleEndedIterator for ResultIter<T, E> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.err.next_back() {
+            Some(err) => Some(Err(err)),
+            None => self.ok.next_back().map(Ok),
+        }
+    }
+}
+
+impl<T: IntoEnumIterator, E: IntoEnumIterator> ExactSizeIterator for ResultIter<T, E> {
+    fn len(&self) -> usize {
+        self.ok.len() + self.err.len()
+    }
+}
+
+impl<T: IntoEnumIterator, E: IntoEnumIterator> FusedIterator for ResultIter<T, E> {}
+
+impl<T: IntoEnumIterator, E: IntoEnumIterator> Clone for ResultIter<T, E> {
+    fn clone(&self) -> Self {
+        ResultIter {
+            ok: self.ok.clone(),
+            err: self.err.clone(),
+        }
+    }
+}
+
+impl<T: IntoEnumIterator, E: IntoEnumIterator> fmt::Debug for ResultIter<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResultIter")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+/// An iterator over every combination of the values of the elements of a tuple.
+/// See the [module documentation](self).
+pub struct TupleIter<T> {
+    // Indices of the next values to return from the front and the back (exclusive).
+    front: usize,
+    back: usize,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for TupleIter<T> {
+    fn clone(&self) -> Self {
+        TupleIter {
+            front: self.front,
+            back: self.back,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for TupleIter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TupleIter")
+            .field("len", &(self.back - self.front))
+            .finish()
+    }
+}
+
+macro_rules! impl_into_enum_iterator_for_tuple {
+    ($len:literal; $($t:ident $i:tt),+) => {
+        impl<$($t: IntoEnumIterator),+> TupleIter<($($t,)+)> {
+            fn lens() -> [usize; $len] {
+                [$($t::iter().len()),+]
+            }
+
+            // The last element varies the fastest.
+            fn get(idx: usize) -> Option<($($t,)+)> {
+                let lens = Self::lens();
+                let mut indices = [0; $len];
+                let mut rest = idx;
+                for i in (0..$len).rev() {
+                    indices[i] = rest % lens[i];
+                    rest /= lens[i];
+                }
+                Some(($($t::iter().nth(indices[$i])?,)+))
+            }
+        }
+
+        impl<$($t: IntoEnumIterator),+> IntoEnumIterator for ($($t,)+) {
+            type Iterator = TupleIter<Self>;
+
+            fn iter() -> Self::Iterator {
+                TupleIter {
+                    front: 0,
+                    back: TupleIter::<Self>::lens().iter().product(),
+                    marker: PhantomData,
+                }
+            }
+        }
+
+        impl<$($t: IntoEnumIterator),+> Iterator for TupleIter<($($t,)+)> {
+            type Item = ($($t,)+);
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.front < self.back {
+                    self.front += 1;
+                    Self::get(self.front - 1)
+                } else {
+                    None
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.back - self.front;
+                (len, Some(len))
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.front = self.front.saturating_add(n).min(self.back);
+                self.next()
+            }
+        }
+
+        impl<$($t: IntoEnumIterator),+> DoubleEndedIterator for TupleIter<($($t,)+)> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.front < self.back {
+                    self.back -= 1;
+                    Self::get(self.back)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<$($t: IntoEnumIterator),+> ExactSizeIterator for TupleIter<($($t,)+)> {}
+
+        impl<$($t: IntoEnumIterator),+> FusedIterator for TupleIter<($($t,)+)> {}
+    };
+}
+
+impl_into_enum_iterator_for_tuple!(1; A 0);
+impl_into_enum_iterator_for_tuple!(2; A 0, B 1);
+impl_into_enum_iterator_for_tuple!(3; A 0, B 1, C 2);
+impl_into_enum_iterator_for_tuple!(4; A 0, B 1, C 2, D 3);
+
+/// An iterator over the variants of an enum along with one of their properties. It is returned
+/// by [`IntoEnumIterator::iter_with_props`].
+#[derive(Debug, Clone)]
+pub struct WithProps<'a, I> {
+    iter: I,
+    key: &'a str,
+}
+
+impl<'a, I> WithProps<'a, I> {
+    pub(crate) fn new(iter: I, key: &'a str) -> Self {
+        WithProps { iter, key }
+    }
+}
+
+impl<I> Iterator for WithProps<'_, I>
+where
+    I: Iterator,
+    I::Item: EnumProperty,
+{
+    type Item = (I::Item, Option<&'static str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let variant = self.iter.next()?;
+        let prop = variant.get_str(self.key);
+        Some((variant, prop))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let variant = self.iter.nth(n)?;
+        let prop = variant.get_str(self.key);
+        Some((variant, prop))
+    }
+}
+
+impl<I> DoubleEndedIterator for WithProps<'_, I>
+where
+    I: DoubleEndedIterator,
+    I::Item: EnumProperty,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let variant = self.iter.next_back()?;
+        let prop = variant.get_str(self.key);
+        Some((variant, prop))
+    }
+}
+
+impl<I> ExactSizeIterator for WithProps<'_, I>
+where
+    I: ExactSizeIterator,
+    I::Item: EnumProperty,
+{
+}
+
+impl<I> FusedIterator for WithProps<'_, I>
+where
+    I: FusedIterator,
+    I::Item: EnumProperty,
+{
+}
@@ -35,9 +35,15 @@
 //! | [EnumProperty]      | Add custom properties to enum variants.                                                                  |
 //! | [EnumMessage]       | Add a verbose message to enum variants.                                                                  |
 //! | [EnumDiscriminants] | Generate a new type with only the discriminant names.                                                    |
+//! | [EnumCycle]         | Adds `next`, `prev` and wrapping variants to navigate between variants.                                  |
+//! | [EnumIndex]         | Maps variants to dense indices and back.                                                                 |
+//! | [EnumArray]         | Lets an enum be used as the key of the generic `EnumMap<K, V>`.                                          |
 //! | [EnumCount]         | Add a constant `usize` equal to the number of variants.                                                  |
 //! | [VariantArray]      | Adds an associated `VARIANTS` constant which is an array of all enum discriminants                       |
 //! | [VariantNames]      | Adds an associated `VARIANTS` constant which is an array of discriminant names                           |
+//! | [VariantIterator]   | Iterates over the unit variants of an enum, without requiring `Default` fields.                          |
+//! | [VariantMetadata]   | Gives the number and names of the variants of an enum and the name of any variant.                       |
+//! | [EnumSet]           | Creates a compact, `Copy` bitset of the variants of an enum.                                             |
 //! | [EnumTable]         | *Experimental*, creates a new type that stores an item of a specified type for each variant of the enum. |
 //!
 //! [EnumString]: https://docs.rs/gratte/latest/gratte/derive.EnumString.html
@@ -50,9 +56,15 @@
 //! [EnumMessage]: https://docs.rs/gratte/latest/gratte/derive.EnumMessage.html
 //! [EnumDiscriminants]: https://docs.rs/gratte/latest/gratte/derive.EnumDiscriminants.html
 //! [EnumCount]: https://docs.rs/gratte/latest/gratte/derive.EnumCount.html
+//! [EnumIndex]: https://docs.rs/gratte/latest/gratte/derive.EnumIndex.html
+//! [EnumArray]: https://docs.rs/gratte/latest/gratte/derive.EnumArray.html
+//! [EnumSet]: https://docs.rs/gratte/latest/gratte/derive.EnumSet.html
+//! [EnumCycle]: https://docs.rs/gratte/latest/gratte/derive.EnumCycle.html
 //! [FromRepr]: https://docs.rs/gratte/latest/gratte/derive.FromRepr.html
 //! [VariantArray]: https://docs.rs/gratte/latest/gratte/derive.VariantArray.html
 //! [VariantNames]: https://docs.rs/gratte/latest/gratte/derive.VariantNames.html
+//! [VariantIterator]: https://docs.rs/gratte/latest/gratte/derive.VariantIterator.html
+//! [VariantMetadata]: https://docs.rs/gratte/latest/gratte/derive.VariantMetadata.html
 //! [EnumTable]: https://docs.rs/gratte/latest/gratte/derive.EnumTable.html
 
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -62,12 +74,114 @@
 // only for documentation purposes
 pub mod additional_attributes;
 
+pub mod enum_map;
+pub mod iter;
+
+#[cfg(feature = "rayon")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub mod rayon;
+
+#[cfg(feature = "testing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "testing")))]
+pub mod testing;
+
 use core::iter::FusedIterator;
 
+pub use enum_map::EnumMap;
+
 #[cfg(feature = "phf")]
 #[doc(hidden)]
 pub use phf as _private_phf_reexport_for_macro_if_phf_feature;
 
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+pub use rand as _private_rand_reexport_for_macro_if_rand_feature;
+
+// Used by the macros to generate code that depends on the `rand`, `rayon` and `serde` features of
+// this crate, which the macros crate can't see.
+#[cfg(feature = "rand")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_if_rand_feature {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "rand"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_if_rand_feature {
+    ($($item:item)*) => {};
+}
+
+#[cfg(feature = "rayon")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_if_rayon_feature {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "rayon"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_if_rayon_feature {
+    ($($item:item)*) => {};
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_if_serde_feature {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _private_if_serde_feature {
+    ($($item:item)*) => {};
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde as _private_serde_reexport_for_macro_if_serde_feature;
+
+// Used by the macros to concatenate non-literal strings (for example, a `prefix` that
+// refers to a constant) in a `const` context.
+#[doc(hidden)]
+pub const fn _private_concat_str_len(parts: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+#[doc(hidden)]
+pub const fn _private_concat_str_bytes<const N: usize>(parts: &[&str]) -> [u8; N] {
+    let mut bytes = [0; N];
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let part = parts[i].as_bytes();
+        let mut j = 0;
+        while j < part.len() {
+            bytes[len] = part[j];
+            len += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    bytes
+}
+
 /// The `ParseError` enum is a collection of all the possible reasons
 /// an enum can fail to parse from a string.
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
@@ -97,6 +211,20 @@ impl std::error::Error for ParseError {
     }
 }
 
+/// The error returned by the `TryFrom` implementations generated by
+/// [`FromRepr`](derive.FromRepr.html), holding the value that doesn't match any variant.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FromReprError<T>(pub T);
+
+impl<T: core::fmt::Display> core::fmt::Display for FromReprError<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        write!(f, "No variant matches the discriminant {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: core::fmt::Debug + core::fmt::Display> std::error::Error for FromReprError<T> {}
+
 /// This trait designates that an `Enum` can be iterated over. It can
 /// be auto generated using the [`EnumIter`](derive.EnumIter.html) derive macro.
 ///
@@ -136,18 +264,62 @@ pub trait IntoEnumIterator: Sized {
         + FusedIterator;
 
     fn iter() -> Self::Iterator;
+
+    /// Returns a parallel iterator over the variants of this enum.
+    #[cfg(feature = "rayon")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+    fn par_iter() -> rayon::ParIter<Self::Iterator>
+    where
+        Self: Send,
+        Self::Iterator: Send,
+    {
+        rayon::ParIter::new(Self::iter())
+    }
+
+    /// Returns an iterator over the variants of this enum along with their message, as returned
+    /// by [`EnumMessage::get_message`].
+    #[allow(clippy::type_complexity)]
+    fn iter_with_messages(
+    ) -> core::iter::Map<Self::Iterator, fn(Self) -> (Self, Option<&'static str>)>
+    where
+        Self: EnumMessage,
+    {
+        Self::iter().map(|variant| {
+            let message = variant.get_message();
+            (variant, message)
+        })
+    }
+
+    /// Returns an iterator over the variants of this enum along with their `key` property, as
+    /// returned by [`EnumProperty::get_str`].
+    fn iter_with_props(key: &str) -> iter::WithProps<'_, Self::Iterator>
+    where
+        Self: EnumProperty,
+    {
+        iter::WithProps::new(Self::iter(), key)
+    }
 }
 
+/// Iterates over the unit variants of an enum. Unlike [`IntoEnumIterator`], this doesn't require
+/// building the fields of data-carrying variants, which are skipped instead. They are skipped even
+/// when their fields implement `Default`, so that the variants yielded don't depend on the types of
+/// the fields: use [`IntoEnumIterator`] to build them from default values. It can be auto
+/// generated using the [`VariantIterator`](derive.VariantIterator.html) derive macro.
 pub trait VariantIterator: Sized {
     type Iterator: Iterator<Item = Self>;
 
     fn iter() -> Self::Iterator;
 }
 
+/// Gives the number and names of the variants of an enum, as well as the name of any variant.
+/// It can be auto generated using the [`VariantMetadata`](derive.VariantMetadata.html) derive macro.
 pub trait VariantMetadata {
+    /// Number of variants of this enum
     const VARIANT_COUNT: usize;
+    /// Names of the variants of this enum
     const VARIANT_NAMES: &'static [&'static str];
 
+    /// Returns the name of this variant.
     fn variant_name(&self) -> &'static str;
 }
 
@@ -237,6 +409,41 @@ pub trait EnumCount {
     const COUNT: usize;
 }
 
+/// A trait for mapping the variants of an Enum to dense indices, from `0` to `COUNT`
+/// (exclusive). This trait can be autoderived by `gratte_macros`, which also adds `const` versions
+/// of the methods and of `COUNT` to the enum itself.
+pub trait EnumIndex: Sized {
+    /// Number of indexed variants. Derived implementations have the same value as
+    /// [`EnumCount::COUNT`]; generic code bounded by both traits must name the one it uses, as
+    /// in `<T as EnumIndex>::COUNT`.
+    const COUNT: usize;
+
+    /// Returns the index of this variant.
+    ///
+    /// # Panics
+    ///
+    /// If the variant has no index, e.g. because it is skipped for `iter` in derived
+    /// implementations. Use [`try_index`](EnumIndex::try_index) to handle these variants.
+    fn index(&self) -> usize;
+
+    /// Returns the index of this variant, or `None` if it has no index.
+    fn try_index(&self) -> Option<usize>;
+
+    /// Returns the variant at the given index, or `None` if the index is out of bounds.
+    fn from_index(index: usize) -> Option<Self>;
+}
+
+/// A trait for enums that can be used as the keys of an [`EnumMap`], giving the type of an array
+/// holding a value of type `V` for each indexed variant. This trait can be autoderived by
+/// `gratte_macros`, along with [`EnumIndex`].
+pub trait EnumArray<V>: EnumIndex {
+    /// The array of values, `[V; Self::COUNT]` for derived implementations
+    type Array: AsRef<[V]> + AsMut<[V]> + IntoIterator<Item = V>;
+
+    /// Creates an array by calling `func` with each index, in order.
+    fn array_from_fn<F: FnMut(usize) -> V>(func: F) -> Self::Array;
+}
+
 /// A trait for retrieving the names of each variant in Enum. This trait can
 /// be autoderived by `gratte_macros`.
 pub trait VariantNames {
@@ -253,6 +460,20 @@ pub trait IntoDiscriminant {
     fn discriminant(&self) -> Self::Discriminant;
 }
 
+/// A trait for converting an Enum from and into the integer representation of its variants. This
+/// trait can be autoderived by `gratte_macros`, which also adds the methods to the enum itself, as
+/// `const fn` when possible.
+pub trait FromRepr: Sized {
+    /// The integer type of the discriminants, given by the `#[repr]` attribute of the enum
+    type Repr;
+
+    /// Returns the variant with the given discriminant, or `None` if there isn't any.
+    fn from_repr(repr: Self::Repr) -> Option<Self>;
+
+    /// Returns the discriminant of this variant.
+    fn to_repr(&self) -> Self::Repr;
+}
+
 /// A trait for retrieving a static array containing all the variants in an Enum.
 /// This trait can be autoderived by `gratte_macros`. For derived usage, all the
 /// variants in the enumerator need to be unit-types, which means you can't autoderive
@@ -261,6 +482,18 @@ pub trait IntoDiscriminant {
 /// static array of variants.
 pub trait VariantArray: ::core::marker::Sized + 'static {
     const VARIANTS: &'static [Self];
+
+    /// Returns a random variant, or `None` if the enum has no variants. Variants are picked
+    /// uniformly, unless the trait is derived and some variants have a `#[strum(weight = ...)]`
+    /// attribute.
+    #[cfg(feature = "rand")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
+    fn random<R: rand::Rng + ?Sized>(rng: &mut R) -> Option<&'static Self> {
+        if Self::VARIANTS.is_empty() {
+            return None;
+        }
+        Some(&Self::VARIANTS[rng.random_range(0..Self::VARIANTS.len())])
+    }
 }
 
 #[cfg(feature = "derive")]
@@ -282,15 +515,21 @@ macro_rules! DocumentMacroRexports {
 // 2018 edition is almost 2 years old, but we'll need to give people some time to do that.
 DocumentMacroRexports! {
     AsRefStr,
+    EnumArray,
     Display,
     EnumCount,
+    EnumCycle,
     EnumDiscriminants,
+    EnumIndex,
     EnumIter,
     EnumMessage,
     EnumProperty,
+    EnumSet,
     EnumString,
     VariantNames,
     FromRepr,
     IntoStaticStr,
-    VariantArray
+    VariantArray,
+    VariantIterator,
+    VariantMetadata
 }
@@ -0,0 +1,128 @@
+//! Support for iterating over the variants of an enum in parallel using [`rayon`](https://docs.rs/rayon).
+//!
+//! Any [`IntoEnumIterator`](crate::IntoEnumIterator) can be iterated in parallel by calling
+//! [`par_iter`](crate::IntoEnumIterator::par_iter). The iterators generated by
+//! [`EnumIter`](https://docs.rs/gratte/latest/gratte/derive.EnumIter.html) also implement
+//! [`IntoParallelIterator`].
+//!
+//! ```rust
+//! use gratte::{EnumIter, IntoEnumIterator};
+//! use rayon::prelude::*;
+//!
+//! #[derive(Debug, EnumIter)]
+//! enum Region {
+//!     Americas,
+//!     Europe,
+//!     Asia,
+//! }
+//!
+//! let total: usize = Region::par_iter().map(|region| format!("{:?}", region).len()).sum();
+//! assert_eq!(18, total);
+//! ```
+
+use core::iter::Take;
+
+use ::rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use ::rayon::iter::{IndexedParallelIterator, ParallelIterator};
+
+#[doc(hidden)]
+pub use ::rayon as _private_rayon_reexport_for_macro;
+
+/// A parallel iterator over the items of an iterator that supports random access.
+///
+/// The work is split by cloning the iterator and skipping over items, which is cheap for enum
+/// variant iterators.
+#[derive(Debug, Clone)]
+pub struct ParIter<I> {
+    iter: I,
+}
+
+impl<I> ParIter<I> {
+    /// Wraps an iterator so that it can be iterated in parallel.
+    pub fn new(iter: I) -> Self {
+        Self { iter }
+    }
+}
+
+impl<I> ParallelIterator for ParIter<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator + Clone + Send,
+    I::Item: Send,
+{
+    type Item = I::Item;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl<I> IndexedParallelIterator for ParIter<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator + Clone + Send,
+    I::Item: Send,
+{
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        callback.callback(IterProducer {
+            len: self.iter.len(),
+            iter: self.iter,
+        })
+    }
+}
+
+// Produces the first `len` items of `iter`.
+struct IterProducer<I> {
+    iter: I,
+    len: usize,
+}
+
+impl<I> Producer for IterProducer<I>
+where
+    I: DoubleEndedIterator + ExactSizeIterator + Clone + Send,
+    I::Item: Send,
+{
+    type Item = I::Item;
+    type IntoIter = Take<I>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.take(self.len)
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let mut right = self.iter.clone();
+        if index > 0 {
+            right.nth(index - 1);
+        }
+
+        (
+            IterProducer {
+                iter: self.iter,
+                len: index,
+            },
+            IterProducer {
+                iter: right,
+                len: self.len - index,
+            },
+        )
+    }
+}
@@ -0,0 +1,150 @@
+//! Helpers to check, in tests, that the traits derived on an enum agree with each other.
+//!
+//! Each function goes through every variant returned by [`IntoEnumIterator::iter`] and panics
+//! with a message identifying the first offending variant.
+//!
+//! ```rust
+//! use gratte::{AsRefStr, Display, EnumCount, EnumIter, EnumString, FromRepr, VariantNames};
+//! use gratte::testing::*;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, AsRefStr, Display, EnumCount, EnumIter, EnumString, FromRepr, VariantNames)]
+//! #[strum(serialize_all = "snake_case")]
+//! #[repr(u8)]
+//! enum Color {
+//!     Red,
+//!     #[strum(serialize = "lime", to_string = "green")]
+//!     Green,
+//!     DarkBlue,
+//! }
+//!
+//! assert_display_roundtrip::<Color>();
+//! assert_names_consistent::<Color>();
+//! assert_count_consistent::<Color>();
+//! assert_repr_roundtrip::<Color>();
+//! ```
+
+use core::fmt::{Debug, Display};
+use core::str::FromStr;
+
+use crate::{EnumCount, FromRepr, IntoEnumIterator, VariantNames};
+
+/// Checks that the string produced by [`Display`] for every variant is parsed back into the
+/// same variant by [`FromStr`].
+///
+/// # Panics
+///
+/// If a variant's string can't be parsed, or is parsed as a different variant.
+#[track_caller]
+pub fn assert_display_roundtrip<E>()
+where
+    E: IntoEnumIterator + Display + FromStr + PartialEq + Debug,
+    E::Err: Debug,
+{
+    for variant in E::iter() {
+        let s = variant.to_string();
+        match s.parse::<E>() {
+            Ok(parsed) if parsed == variant => {}
+            Ok(parsed) => panic!(
+                "variant `{:?}` is displayed as {:?}, which is parsed as `{:?}`",
+                variant, s, parsed
+            ),
+            Err(err) => panic!(
+                "variant `{:?}` is displayed as {:?}, which fails to parse: {:?}",
+                variant, s, err
+            ),
+        }
+    }
+}
+
+/// Checks that [`VariantNames::VARIANTS`] lists the name of every variant, in the order
+/// returned by [`IntoEnumIterator::iter`]. The name of each variant is obtained via `AsRef<str>`.
+///
+/// # Panics
+///
+/// If a variant's name doesn't match the name at the same position in `VARIANTS`, or if
+/// `VARIANTS` doesn't have as many names as there are variants.
+#[track_caller]
+pub fn assert_names_consistent<E>()
+where
+    E: IntoEnumIterator + VariantNames + AsRef<str> + Debug,
+{
+    let mut count = 0;
+    for (i, variant) in E::iter().enumerate() {
+        match E::VARIANTS.get(i) {
+            Some(&name) if name == variant.as_ref() => {}
+            Some(name) => panic!(
+                "variant `{:?}` is named {:?}, but `VARIANTS[{}]` is {:?}",
+                variant,
+                variant.as_ref(),
+                i,
+                name
+            ),
+            None => panic!(
+                "variant `{:?}` is named {:?}, but `VARIANTS` has only {} entries",
+                variant,
+                variant.as_ref(),
+                E::VARIANTS.len()
+            ),
+        }
+        count += 1;
+    }
+
+    assert_eq!(
+        count,
+        E::VARIANTS.len(),
+        "`VARIANTS` has more entries than there are variants; first extra entry is {:?}",
+        E::VARIANTS.get(count)
+    );
+}
+
+/// Checks that [`EnumCount::COUNT`] is equal to the number of variants returned by
+/// [`IntoEnumIterator::iter`].
+///
+/// # Panics
+///
+/// If the counts differ.
+#[track_caller]
+pub fn assert_count_consistent<E>()
+where
+    E: IntoEnumIterator + EnumCount,
+{
+    let count = E::iter().count();
+    assert_eq!(
+        E::COUNT,
+        count,
+        "`COUNT` is {} but iterating yields {} variants",
+        E::COUNT,
+        count
+    );
+}
+
+/// Checks that every variant is converted back into the same variant by
+/// [`FromRepr::from_repr`] when passed the value returned by [`FromRepr::to_repr`].
+///
+/// # Panics
+///
+/// If `from_repr` returns `None` or a different variant for a variant's representation.
+#[track_caller]
+pub fn assert_repr_roundtrip<E>()
+where
+    E: IntoEnumIterator + FromRepr + PartialEq + Debug,
+    E::Repr: Debug,
+{
+    for variant in E::iter() {
+        let repr = variant.to_repr();
+        match E::from_repr(repr) {
+            Some(parsed) if parsed == variant => {}
+            Some(parsed) => panic!(
+                "variant `{:?}` has repr {:?}, which is converted to `{:?}`",
+                variant,
+                variant.to_repr(),
+                parsed
+            ),
+            None => panic!(
+                "variant `{:?}` has repr {:?}, which is not converted to any variant",
+                variant,
+                variant.to_repr()
+            ),
+        }
+    }
+}
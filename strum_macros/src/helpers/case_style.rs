@@ -85,6 +85,12 @@ pub trait CaseStyleHelpers {
 }
 
 impl CaseStyleHelpers for Ident {
+    fn convert_case(&self, case_style: Option<CaseStyle>) -> String {
+        self.to_string().as_str().convert_case(case_style)
+    }
+}
+
+impl CaseStyleHelpers for str {
     fn convert_case(&self, case_style: Option<CaseStyle>) -> String {
         let ident_string = self.to_string();
         if let Some(case_style) = case_style {
@@ -1,6 +1,6 @@
 use super::metadata::{InnerVariantExt, InnerVariantMeta};
 use super::occurrence_error;
-use syn::{Field, LitStr};
+use syn::{Expr, Field, LitStr};
 
 pub trait HasInnerVariantProperties {
     fn get_variant_inner_properties(&self) -> syn::Result<StrumInnerVariantProperties>;
@@ -9,13 +9,15 @@ pub trait HasInnerVariantProperties {
 #[derive(Clone, Default)]
 pub struct StrumInnerVariantProperties {
     pub default_with: Option<LitStr>,
+    pub iter_values: Option<Vec<Expr>>,
 }
 
 impl HasInnerVariantProperties for Field {
     fn get_variant_inner_properties(&self) -> syn::Result<StrumInnerVariantProperties> {
-        let mut output = StrumInnerVariantProperties { default_with: None };
+        let mut output = StrumInnerVariantProperties::default();
 
         let mut default_with_kw = None;
+        let mut iter_values_kw = None;
         for meta in self.get_named_metadata()? {
             match meta {
                 InnerVariantMeta::DefaultWith { kw, value } => {
@@ -25,6 +27,14 @@ impl HasInnerVariantProperties for Field {
                     default_with_kw = Some(kw);
                     output.default_with = Some(value);
                 }
+                InnerVariantMeta::IterValues { kw, values } => {
+                    if let Some(fst_kw) = iter_values_kw {
+                        return Err(occurrence_error(fst_kw, kw, "iter_values"));
+                    }
+
+                    iter_values_kw = Some(kw);
+                    output.iter_values = Some(values);
+                }
             }
         }
 
@@ -1,13 +1,14 @@
 use syn::{
-    parenthesized,
+    bracketed, parenthesized,
     parse::{Parse, ParseStream},
     parse2, parse_str,
     punctuated::Punctuated,
-    Attribute, DeriveInput, Expr, ExprLit, Field, Ident, Lit, LitBool, LitStr, Meta, MetaNameValue,
-    Path, Token, Variant, Visibility,
+    Attribute, DeriveInput, Expr, ExprLit, Field, Ident, Lit, LitBool, LitInt, LitStr, Meta,
+    MetaNameValue, Path, Token, Variant, Visibility,
 };
 
 use super::case_style::CaseStyle;
+use super::str_value::StrValue;
 
 pub mod kw {
     use syn::custom_keyword;
@@ -19,8 +20,15 @@ pub mod kw {
     custom_keyword!(use_phf);
     custom_keyword!(prefix);
     custom_keyword!(suffix);
+    custom_keyword!(strip_prefix);
+    custom_keyword!(strip_suffix);
     custom_keyword!(parse_err_ty);
     custom_keyword!(parse_err_fn);
+    custom_keyword!(verify_roundtrip);
+    custom_keyword!(iter_fields);
+    custom_keyword!(table_disabled);
+    custom_keyword!(table_serde);
+    custom_keyword!(table_serde_default);
 
     // enum discriminant metadata
     custom_keyword!(derive);
@@ -35,10 +43,16 @@ pub mod kw {
     custom_keyword!(to_string);
     custom_keyword!(transparent);
     custom_keyword!(disabled);
+    custom_keyword!(skip);
     custom_keyword!(default);
     custom_keyword!(default_with);
+    custom_keyword!(catch_all);
     custom_keyword!(props);
     custom_keyword!(ascii_case_insensitive);
+    custom_keyword!(weight);
+
+    // variant field metadata
+    custom_keyword!(iter_values);
 }
 
 pub enum EnumMeta {
@@ -54,10 +68,18 @@ pub enum EnumMeta {
     UsePhf(kw::use_phf),
     Prefix {
         kw: kw::prefix,
-        prefix: LitStr,
+        prefix: StrValue,
     },
     Suffix {
         kw: kw::suffix,
+        suffix: StrValue,
+    },
+    StripPrefix {
+        kw: kw::strip_prefix,
+        prefix: LitStr,
+    },
+    StripSuffix {
+        kw: kw::strip_suffix,
         suffix: LitStr,
     },
     ParseErrTy {
@@ -69,6 +91,14 @@ pub enum EnumMeta {
         path: Path,
     },
     ConstIntoStr(kw::const_into_str),
+    VerifyRoundtrip(kw::verify_roundtrip),
+    IterFields(kw::iter_fields),
+    TableDisabled {
+        kw: kw::table_disabled,
+        compile_error: bool,
+    },
+    TableSerde(kw::table_serde),
+    TableSerdeDefault(kw::table_serde_default),
 }
 
 impl Parse for EnumMeta {
@@ -103,6 +133,16 @@ impl Parse for EnumMeta {
             input.parse::<Token![=]>()?;
             let suffix = input.parse()?;
             Ok(EnumMeta::Suffix { kw, suffix })
+        } else if lookahead.peek(kw::strip_prefix) {
+            let kw = input.parse::<kw::strip_prefix>()?;
+            input.parse::<Token![=]>()?;
+            let prefix = input.parse()?;
+            Ok(EnumMeta::StripPrefix { kw, prefix })
+        } else if lookahead.peek(kw::strip_suffix) {
+            let kw = input.parse::<kw::strip_suffix>()?;
+            input.parse::<Token![=]>()?;
+            let suffix = input.parse()?;
+            Ok(EnumMeta::StripSuffix { kw, suffix })
         } else if lookahead.peek(kw::parse_err_ty) {
             let kw = input.parse::<kw::parse_err_ty>()?;
             input.parse::<Token![=]>()?;
@@ -115,6 +155,29 @@ impl Parse for EnumMeta {
             Ok(EnumMeta::ParseErrFn { kw, path })
         } else if lookahead.peek(kw::const_into_str) {
             Ok(EnumMeta::ConstIntoStr(input.parse()?))
+        } else if lookahead.peek(kw::verify_roundtrip) {
+            Ok(EnumMeta::VerifyRoundtrip(input.parse()?))
+        } else if lookahead.peek(kw::iter_fields) {
+            Ok(EnumMeta::IterFields(input.parse()?))
+        } else if lookahead.peek(kw::table_disabled) {
+            let kw = input.parse::<kw::table_disabled>()?;
+            input.parse::<Token![=]>()?;
+            let mode: LitStr = input.parse()?;
+            let compile_error = match mode.value().as_str() {
+                "panic" => false,
+                "compile_error" => true,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        mode,
+                        "Unexpected value for table_disabled. Valid values are: `\"panic\"`, `\"compile_error\"`",
+                    ))
+                }
+            };
+            Ok(EnumMeta::TableDisabled { kw, compile_error })
+        } else if lookahead.peek(kw::table_serde) {
+            Ok(EnumMeta::TableSerde(input.parse()?))
+        } else if lookahead.peek(kw::table_serde_default) {
+            Ok(EnumMeta::TableSerdeDefault(input.parse()?))
         } else {
             Err(lookahead.error())
         }
@@ -179,26 +242,31 @@ impl DeriveInputExt for DeriveInput {
 pub enum VariantMeta {
     Message {
         kw: kw::message,
-        value: LitStr,
+        value: StrValue,
     },
     DetailedMessage {
         kw: kw::detailed_message,
-        value: LitStr,
+        value: StrValue,
     },
     Serialize {
         _kw: kw::serialize,
-        value: LitStr,
+        value: StrValue,
     },
     Documentation {
         value: LitStr,
     },
     ToString {
         kw: kw::to_string,
-        value: LitStr,
+        value: StrValue,
     },
     Transparent(kw::transparent),
     Disabled(kw::disabled),
+    Skip {
+        _kw: kw::skip,
+        targets: Vec<SkipTarget>,
+    },
     Default(kw::default),
+    CatchAll(kw::catch_all),
     DefaultWith {
         kw: kw::default_with,
         value: LitStr,
@@ -211,6 +279,11 @@ pub enum VariantMeta {
         _kw: kw::props,
         props: Vec<(LitStr, Lit)>,
     },
+    IterFields(kw::iter_fields),
+    Weight {
+        kw: kw::weight,
+        value: LitInt,
+    },
 }
 
 impl Parse for VariantMeta {
@@ -240,8 +313,19 @@ impl Parse for VariantMeta {
             Ok(VariantMeta::Transparent(input.parse()?))
         } else if lookahead.peek(kw::disabled) {
             Ok(VariantMeta::Disabled(input.parse()?))
+        } else if lookahead.peek(kw::skip) {
+            let _kw = input.parse()?;
+            let content;
+            parenthesized!(content in input);
+            let targets = content.parse_terminated(SkipTarget::parse, Token![,])?;
+            Ok(VariantMeta::Skip {
+                _kw,
+                targets: targets.into_iter().collect(),
+            })
         } else if lookahead.peek(kw::default) {
             Ok(VariantMeta::Default(input.parse()?))
+        } else if lookahead.peek(kw::catch_all) {
+            Ok(VariantMeta::CatchAll(input.parse()?))
         } else if lookahead.peek(kw::default_with) {
             let kw = input.parse()?;
             let _: Token![=] = input.parse()?;
@@ -268,12 +352,77 @@ impl Parse for VariantMeta {
                     .map(|Prop(k, v)| (LitStr::new(&k.to_string(), k.span()), v))
                     .collect(),
             })
+        } else if lookahead.peek(kw::iter_fields) {
+            Ok(VariantMeta::IterFields(input.parse()?))
+        } else if lookahead.peek(kw::weight) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let value = input.parse()?;
+            Ok(VariantMeta::Weight { kw, value })
         } else {
             Err(lookahead.error())
         }
     }
 }
 
+/// The features a variant can be excluded from using `#[strum(skip(...))]`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SkipTarget {
+    /// `EnumString`
+    Parse,
+    /// `Display`, `AsRefStr` and `IntoStaticStr`
+    Display,
+    /// `VariantNames`
+    Names,
+    /// `EnumIter`, `VariantArray` and `EnumCount`
+    Iter,
+    /// `EnumMessage`
+    Messages,
+    /// `EnumProperty`
+    Props,
+    /// `FromRepr`
+    Repr,
+    /// `EnumTable`
+    Table,
+    /// `EnumIs`
+    Is,
+    /// `EnumTryAs`
+    TryAs,
+}
+
+const VALID_SKIP_TARGETS: &[&str] = &[
+    "parse", "display", "names", "iter", "messages", "props", "repr", "table", "is", "try_as",
+];
+
+impl Parse for SkipTarget {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        use syn::ext::IdentExt;
+
+        let ident = Ident::parse_any(input)?;
+        Ok(match ident.to_string().as_str() {
+            "parse" => SkipTarget::Parse,
+            "display" => SkipTarget::Display,
+            "names" => SkipTarget::Names,
+            "iter" => SkipTarget::Iter,
+            "messages" => SkipTarget::Messages,
+            "props" => SkipTarget::Props,
+            "repr" => SkipTarget::Repr,
+            "table" => SkipTarget::Table,
+            "is" => SkipTarget::Is,
+            "try_as" => SkipTarget::TryAs,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &ident,
+                    format!(
+                        "Unexpected value for skip: `{}`. Valid values are: `{:?}`",
+                        ident, VALID_SKIP_TARGETS
+                    ),
+                ))
+            }
+        })
+    }
+}
+
 struct Prop(Ident, Lit);
 
 impl Parse for Prop {
@@ -331,7 +480,14 @@ fn get_metadata_inner<'a, T: Parse>(
 }
 
 pub enum InnerVariantMeta {
-    DefaultWith { kw: kw::default_with, value: LitStr },
+    DefaultWith {
+        kw: kw::default_with,
+        value: LitStr,
+    },
+    IterValues {
+        kw: kw::iter_values,
+        values: Vec<Expr>,
+    },
 }
 
 impl Parse for InnerVariantMeta {
@@ -342,6 +498,16 @@ impl Parse for InnerVariantMeta {
             let _: Token![=] = input.parse()?;
             let value = input.parse()?;
             Ok(InnerVariantMeta::DefaultWith { kw, value })
+        } else if lookahead.peek(kw::iter_values) {
+            let kw = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            let content;
+            bracketed!(content in input);
+            let values = content.parse_terminated(Expr::parse, Token![,])?;
+            Ok(InnerVariantMeta::IterValues {
+                kw,
+                values: values.into_iter().collect(),
+            })
         } else {
             Err(lookahead.error())
         }
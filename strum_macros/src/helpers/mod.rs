@@ -1,17 +1,24 @@
 pub use self::case_style::snakify;
 pub use self::inner_variant_props::HasInnerVariantProperties;
+pub use self::metadata::SkipTarget;
+pub use self::str_value::StrValue;
 pub use self::type_props::HasTypeProperties;
 pub use self::variant_props::HasStrumVariantProperties;
 
 pub mod case_style;
 pub mod inner_variant_props;
 mod metadata;
+pub mod str_value;
 pub mod type_props;
 pub mod variant_props;
 
-use proc_macro2::Span;
-use quote::ToTokens;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
+use syn::{Fields, GenericParam, Generics, Ident, Token, Type, Variant, WherePredicate};
+
+use self::variant_props::StrumVariantProperties;
 
 pub fn missing_parse_err_attr_error() -> syn::Error {
     syn::Error::new(
@@ -49,6 +56,16 @@ pub fn strum_discriminants_passthrough_error(span: &impl Spanned) -> syn::Error
     )
 }
 
+pub fn add_weight(total: u32, weight: u32, span: &impl ToTokens) -> syn::Result<u32> {
+    total.checked_add(weight).ok_or_else(|| {
+        syn::Error::new_spanned(span, "The sum of the variant weights overflows a `u32`")
+    })
+}
+
+pub fn zero_weight_error(span: &impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(span, "At least one variant must have a non-zero weight")
+}
+
 pub fn occurrence_error<T: ToTokens>(fst: T, snd: T, attr: &str) -> syn::Error {
     let mut e = syn::Error::new_spanned(
         snd,
@@ -57,3 +74,107 @@ pub fn occurrence_error<T: ToTokens>(fst: T, snd: T, attr: &str) -> syn::Error {
     e.combine(syn::Error::new_spanned(fst, "first one here"));
     e
 }
+
+/// Returns `ty: bound` for each of `types` that mentions a type or const parameter of
+/// `generics`, without duplicates. Other types don't need a bound: their impls are either always
+/// there or missing regardless of the parameters.
+pub fn generic_bounds<'a>(
+    types: impl IntoIterator<Item = &'a Type>,
+    generics: &Generics,
+    bound: &TokenStream,
+) -> Vec<WherePredicate> {
+    fn mentions(tokens: TokenStream, params: &[&syn::Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            TokenTree::Ident(ident) => params.contains(&&ident),
+            TokenTree::Group(group) => mentions(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    let params: Vec<_> = generics
+        .type_params()
+        .map(|param| &param.ident)
+        .chain(generics.const_params().map(|param| &param.ident))
+        .collect();
+    let mut seen = Vec::new();
+    let mut bounds = Vec::new();
+    for ty in types {
+        let key = ty.to_token_stream().to_string();
+        if seen.contains(&key) || !mentions(ty.to_token_stream(), &params) {
+            continue;
+        }
+        seen.push(key);
+        bounds.push(syn::parse_quote! { #ty: #bound });
+    }
+    bounds
+}
+
+/// Returns `base`, with enough underscores appended to not clash with the generic parameters of
+/// the enum.
+pub fn fresh_param(generics: &Generics, base: &str) -> Ident {
+    let mut name = base.to_owned();
+    while generics.params.iter().any(|param| match param {
+        GenericParam::Type(param) => param.ident == name,
+        GenericParam::Const(param) => param.ident == name,
+        GenericParam::Lifetime(_) => false,
+    }) {
+        name.push('_');
+    }
+    Ident::new(&name, Span::call_site())
+}
+
+/// A variant of an enum, along with its properties and its position in the dense order of the
+/// variants.
+pub struct DenseVariant<'a> {
+    pub variant: &'a Variant,
+    pub properties: StrumVariantProperties,
+    /// The index of the variant, or `None` if it is left out of the dense order.
+    pub index: Option<usize>,
+}
+
+/// Lists the variants of an enum with their indices in the dense order shared by `EnumIter`,
+/// `EnumCount`, `EnumIndex`, `EnumArray`, `EnumSet`, `EnumCycle` and `VariantArray`. Variants
+/// are numbered in declaration order, leaving out those skipped for `iter` and the `catch_all`
+/// variant, whose values stand for unknown discriminants.
+pub fn dense_variants(
+    variants: &Punctuated<Variant, Token![,]>,
+) -> syn::Result<Vec<DenseVariant<'_>>> {
+    let mut count = 0;
+    variants
+        .iter()
+        .map(|variant| {
+            let properties = variant.get_variant_properties()?;
+            let index = if properties.is_skipped(SkipTarget::Iter) || properties.catch_all.is_some()
+            {
+                None
+            } else {
+                count += 1;
+                Some(count - 1)
+            };
+            Ok(DenseVariant {
+                variant,
+                properties,
+                index,
+            })
+        })
+        .collect()
+}
+
+/// Returns the fields to build a variant with, each set to `Default::default()`.
+pub fn default_fields(fields: &Fields) -> TokenStream {
+    match fields {
+        Fields::Unit => quote! {},
+        Fields::Unnamed(fields) => {
+            let defaults = ::core::iter::repeat(quote!(::core::default::Default::default()))
+                .take(fields.unnamed.len());
+            quote! { (#(#defaults),*) }
+        }
+        Fields::Named(fields) => {
+            let fields = fields
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().unwrap());
+            quote! { {#(#fields: ::core::default::Default::default()),*} }
+        }
+    }
+}
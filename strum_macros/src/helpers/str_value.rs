@@ -0,0 +1,94 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    Expr, ExprLit, Lit, LitStr, Path,
+};
+
+/// A string value passed to a `strum` attribute like `serialize`, `to_string` or `prefix`.
+///
+/// Besides string literals, these attributes accept paths to `&'static str` constants and
+/// macro invocations like `concat!` or `stringify!`. The value of those is only known once
+/// the generated code is compiled, so they are emitted as-is.
+#[derive(Clone)]
+pub enum StrValue {
+    Lit(LitStr),
+    Expr(Expr),
+}
+
+impl StrValue {
+    pub fn as_lit(&self) -> Option<&LitStr> {
+        match self {
+            StrValue::Lit(lit) => Some(lit),
+            StrValue::Expr(_) => None,
+        }
+    }
+
+    /// Returns the string literal, or an error explaining that `usage` needs to know the value
+    /// when the macro runs.
+    pub fn require_lit(&self, usage: &str) -> syn::Result<&LitStr> {
+        self.as_lit().ok_or_else(|| {
+            syn::Error::new_spanned(
+                self,
+                format!(
+                    "{} needs to know this value when the macro runs, so it must be a string literal",
+                    usage
+                ),
+            )
+        })
+    }
+
+    /// Concatenates multiple values into one. If all values are literals, the result is a
+    /// literal; otherwise, it is a block concatenating the values in a `const` context.
+    pub fn concat(values: &[&StrValue], strum_module_path: &Path, span: Span) -> StrValue {
+        let lits: Option<Vec<_>> = values.iter().map(|value| value.as_lit()).collect();
+        if let Some(lits) = lits {
+            let value: String = lits.iter().map(|lit| lit.value()).collect();
+            return StrValue::Lit(LitStr::new(&value, span));
+        }
+
+        StrValue::Expr(Expr::Verbatim(quote! {
+            {
+                const PARTS: &[&str] = &[#(#values),*];
+                const LEN: usize = #strum_module_path::_private_concat_str_len(PARTS);
+                const BYTES: [u8; LEN] = #strum_module_path::_private_concat_str_bytes::<LEN>(PARTS);
+                const VALUE: &str = match ::core::str::from_utf8(&BYTES) {
+                    ::core::result::Result::Ok(value) => value,
+                    ::core::result::Result::Err(_) => ::core::panic!("concatenated strings are not valid UTF-8"),
+                };
+                VALUE
+            }
+        }))
+    }
+}
+
+impl From<LitStr> for StrValue {
+    fn from(lit: LitStr) -> Self {
+        StrValue::Lit(lit)
+    }
+}
+
+impl Parse for StrValue {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        match input.parse()? {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) => Ok(StrValue::Lit(lit)),
+            expr @ (Expr::Path(_) | Expr::Macro(_)) => Ok(StrValue::Expr(expr)),
+            expr => Err(syn::Error::new_spanned(
+                expr,
+                "expected a string literal, a path to a `&'static str` constant or a macro \
+                 invocation like `concat!(...)`",
+            )),
+        }
+    }
+}
+
+impl ToTokens for StrValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            StrValue::Lit(lit) => lit.to_tokens(tokens),
+            StrValue::Expr(expr) => expr.to_tokens(tokens),
+        }
+    }
+}
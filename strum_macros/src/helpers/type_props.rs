@@ -1,10 +1,16 @@
 use proc_macro2::TokenStream;
+use std::collections::HashMap;
 use std::default::Default;
-use syn::{parse_quote, DeriveInput, Ident, LitStr, Meta, Path, Visibility};
+use syn::{
+    parse_quote, punctuated::Punctuated, Data, DeriveInput, Ident, LitStr, Meta, Path, Token,
+    Variant, Visibility,
+};
 
-use super::case_style::CaseStyle;
+use super::case_style::{CaseStyle, CaseStyleHelpers};
 use super::metadata::{DeriveInputExt, EnumDiscriminantsMeta, EnumMeta};
 use super::occurrence_error;
+use super::str_value::StrValue;
+use super::variant_props::HasStrumVariantProperties;
 
 pub trait HasTypeProperties {
     fn get_type_properties(&self) -> syn::Result<StrumTypeProperties>;
@@ -22,10 +28,17 @@ pub struct StrumTypeProperties {
     pub discriminant_others: Vec<Meta>,
     pub discriminant_vis: Option<Visibility>,
     pub use_phf: bool,
-    pub prefix: Option<LitStr>,
-    pub suffix: Option<LitStr>,
+    pub prefix: Option<StrValue>,
+    pub suffix: Option<StrValue>,
+    pub strip_prefix: Option<LitStr>,
+    pub strip_suffix: Option<LitStr>,
     pub enum_repr: Option<TokenStream>,
     pub const_into_str: bool,
+    pub verify_roundtrip: bool,
+    pub iter_fields: bool,
+    pub table_disabled_compile_error: bool,
+    pub table_serde: bool,
+    pub table_serde_default: bool,
 }
 
 impl HasTypeProperties for DeriveInput {
@@ -43,7 +56,14 @@ impl HasTypeProperties for DeriveInput {
         let mut crate_module_path_kw = None;
         let mut prefix_kw = None;
         let mut suffix_kw = None;
+        let mut strip_prefix_kw = None;
+        let mut strip_suffix_kw = None;
         let mut const_into_str = None;
+        let mut verify_roundtrip_kw = None;
+        let mut iter_fields_kw = None;
+        let mut table_disabled_kw = None;
+        let mut table_serde_kw = None;
+        let mut table_serde_default_kw = None;
 
         for meta in strum_meta {
             match meta {
@@ -98,6 +118,22 @@ impl HasTypeProperties for DeriveInput {
                     suffix_kw = Some(kw);
                     output.suffix = Some(suffix);
                 }
+                EnumMeta::StripPrefix { prefix, kw } => {
+                    if let Some(fst_kw) = strip_prefix_kw {
+                        return Err(occurrence_error(fst_kw, kw, "strip_prefix"));
+                    }
+
+                    strip_prefix_kw = Some(kw);
+                    output.strip_prefix = Some(prefix);
+                }
+                EnumMeta::StripSuffix { suffix, kw } => {
+                    if let Some(fst_kw) = strip_suffix_kw {
+                        return Err(occurrence_error(fst_kw, kw, "strip_suffix"));
+                    }
+
+                    strip_suffix_kw = Some(kw);
+                    output.strip_suffix = Some(suffix);
+                }
                 EnumMeta::ParseErrTy { path, kw } => {
                     if let Some(fst_kw) = parse_err_ty_kw {
                         return Err(occurrence_error(fst_kw, kw, "parse_err_ty"));
@@ -122,6 +158,47 @@ impl HasTypeProperties for DeriveInput {
                     const_into_str = Some(kw);
                     output.const_into_str = true;
                 }
+                EnumMeta::VerifyRoundtrip(kw) => {
+                    if let Some(fst_kw) = verify_roundtrip_kw {
+                        return Err(occurrence_error(fst_kw, kw, "verify_roundtrip"));
+                    }
+
+                    verify_roundtrip_kw = Some(kw);
+                    output.verify_roundtrip = true;
+                }
+                EnumMeta::IterFields(kw) => {
+                    if let Some(fst_kw) = iter_fields_kw {
+                        return Err(occurrence_error(fst_kw, kw, "iter_fields"));
+                    }
+
+                    iter_fields_kw = Some(kw);
+                    output.iter_fields = true;
+                }
+                EnumMeta::TableDisabled { kw, compile_error } => {
+                    if let Some(fst_kw) = table_disabled_kw {
+                        return Err(occurrence_error(fst_kw, kw, "table_disabled"));
+                    }
+
+                    table_disabled_kw = Some(kw);
+                    output.table_disabled_compile_error = compile_error;
+                }
+                EnumMeta::TableSerde(kw) => {
+                    if let Some(fst_kw) = table_serde_kw {
+                        return Err(occurrence_error(fst_kw, kw, "table_serde"));
+                    }
+
+                    table_serde_kw = Some(kw);
+                    output.table_serde = true;
+                }
+                EnumMeta::TableSerdeDefault(kw) => {
+                    if let Some(fst_kw) = table_serde_default_kw {
+                        return Err(occurrence_error(fst_kw, kw, "table_serde_default"));
+                    }
+
+                    table_serde_default_kw = Some(kw);
+                    output.table_serde = true;
+                    output.table_serde_default = true;
+                }
             }
         }
 
@@ -165,6 +242,12 @@ impl HasTypeProperties for DeriveInput {
             }
         }
 
+        if output.strip_prefix.is_some() || output.strip_suffix.is_some() {
+            if let Data::Enum(data) = &self.data {
+                output.check_stripped_names(&data.variants)?;
+            }
+        }
+
         Ok(output)
     }
 }
@@ -175,4 +258,81 @@ impl StrumTypeProperties {
             .as_ref()
             .map_or_else(|| parse_quote!(::gratte), |path| parse_quote!(#path))
     }
+
+    /// Returns the name of a variant identifier once `strip_prefix` and `strip_suffix`
+    /// have been applied, before any case conversion.
+    pub fn strip_ident(&self, ident: &Ident) -> String {
+        let mut name = ident.to_string();
+
+        if let Some(prefix) = &self.strip_prefix {
+            if let Some(stripped) = name.strip_prefix(&prefix.value()) {
+                name = stripped.to_string();
+            }
+        }
+
+        if let Some(suffix) = &self.strip_suffix {
+            if let Some(stripped) = name.strip_suffix(&suffix.value()) {
+                name = stripped.to_string();
+            }
+        }
+
+        name
+    }
+
+    /// Makes sure stripping doesn't leave a variant without a name, and that it doesn't
+    /// give a variant the name of another one, stripped or set with `serialize` or `to_string`.
+    fn check_stripped_names(&self, variants: &Punctuated<Variant, Token![,]>) -> syn::Result<()> {
+        // Explicit names are only compared with stripped ones: two variants sharing a `serialize`
+        // value are allowed regardless of stripping.
+        let mut names: HashMap<String, (&Ident, bool)> = HashMap::new();
+        for variant in variants {
+            let variant_properties = variant.get_variant_properties()?;
+            let ident = &variant.ident;
+            let stripped = variant_properties.uses_ident_name();
+
+            let variant_names = if stripped {
+                let name = self.strip_ident(ident);
+                if name.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        ident,
+                        format!(
+                            "Variant `{}` has an empty name once strum(strip_prefix) and strum(strip_suffix) are applied",
+                            ident
+                        ),
+                    ));
+                }
+                vec![name.as_str().convert_case(self.case_style)]
+            } else {
+                variant_properties
+                    .get_serializations(self)
+                    .iter()
+                    .filter_map(|value| value.as_lit().map(LitStr::value))
+                    .collect()
+            };
+
+            for name in variant_names {
+                match names.get(&name) {
+                    Some(&(fst_ident, fst_stripped))
+                        if fst_ident != ident && (stripped || fst_stripped) =>
+                    {
+                        let mut e = syn::Error::new_spanned(
+                            ident,
+                            format!(
+                                "Variant `{}` and another variant are both named `{}` once strum(strip_prefix) and strum(strip_suffix) are applied",
+                                ident, name
+                            ),
+                        );
+                        e.combine(syn::Error::new_spanned(fst_ident, "first one here"));
+                        return Err(e);
+                    }
+                    Some(_) => {}
+                    None => {
+                        names.insert(name, (ident, stripped));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -1,9 +1,11 @@
 use std::default::Default;
-use syn::{Ident, Lit, LitStr, Variant};
+use syn::{spanned::Spanned, Ident, Lit, LitStr, Variant};
 
-use super::case_style::{CaseStyle, CaseStyleHelpers};
-use super::metadata::{kw, VariantExt, VariantMeta};
+use super::case_style::CaseStyleHelpers;
+use super::metadata::{kw, SkipTarget, VariantExt, VariantMeta};
 use super::occurrence_error;
+use super::str_value::StrValue;
+use super::type_props::StrumTypeProperties;
 
 pub trait HasStrumVariantProperties {
     fn get_variant_properties(&self) -> syn::Result<StrumVariantProperties>;
@@ -13,57 +15,100 @@ pub trait HasStrumVariantProperties {
 pub struct StrumVariantProperties {
     pub transparent: Option<kw::transparent>,
     pub disabled: Option<kw::disabled>,
+    pub skip: Vec<SkipTarget>,
     pub default: Option<kw::default>,
+    pub catch_all: Option<kw::catch_all>,
     pub default_with: Option<LitStr>,
     pub ascii_case_insensitive: Option<bool>,
-    pub message: Option<LitStr>,
-    pub detailed_message: Option<LitStr>,
+    pub message: Option<StrValue>,
+    pub detailed_message: Option<StrValue>,
     pub documentation: Vec<LitStr>,
     pub props: Vec<(LitStr, Lit)>,
-    serialize: Vec<LitStr>,
-    pub to_string: Option<LitStr>,
+    pub iter_fields: Option<kw::iter_fields>,
+    pub weight: Option<u32>,
+    serialize: Vec<StrValue>,
+    pub to_string: Option<StrValue>,
     ident: Option<Ident>,
 }
 
 impl StrumVariantProperties {
-    fn ident_as_str(&self, case_style: Option<CaseStyle>) -> LitStr {
+    fn ident_as_str(&self, type_properties: &StrumTypeProperties) -> LitStr {
         let ident = self.ident.as_ref().expect("identifier");
-        LitStr::new(&ident.convert_case(case_style), ident.span())
+        let name = type_properties.strip_ident(ident);
+        LitStr::new(
+            &name.as_str().convert_case(type_properties.case_style),
+            ident.span(),
+        )
+    }
+
+    /// Returns `true` if the variant is excluded from the code generated for `target`, either
+    /// because it is `disabled` or because `target` is listed in its `skip` attribute.
+    pub fn is_skipped(&self, target: SkipTarget) -> bool {
+        self.disabled.is_some() || self.skip.contains(&target)
+    }
+
+    /// Returns `true` if the serializations of this variant are derived from its identifier,
+    /// e.g. if it has neither a `to_string` nor a `serialize` attribute.
+    pub fn uses_ident_name(&self) -> bool {
+        self.to_string.is_none() && self.serialize.is_empty()
+    }
+
+    /// Returns the relative probability of picking this variant when sampling random variants.
+    pub fn weight(&self) -> u32 {
+        self.weight.unwrap_or(1)
     }
 
     pub fn get_preferred_name(
         &self,
-        case_style: Option<CaseStyle>,
-        prefix: Option<&LitStr>,
-        suffix: Option<&LitStr>,
-    ) -> LitStr {
-        let mut output = self.to_string.as_ref().cloned().unwrap_or_else(|| {
-            self.serialize
-                .iter()
-                .max_by_key(|s| s.value().len())
-                .cloned()
-                .unwrap_or_else(|| self.ident_as_str(case_style))
-        });
-
-        if let Some(prefix) = prefix {
-            output = LitStr::new(&(prefix.value() + &output.value()), output.span());
-        }
+        type_properties: &StrumTypeProperties,
+    ) -> syn::Result<StrValue> {
+        let output = match (&self.to_string, self.serialize.as_slice()) {
+            (Some(to_string), _) => to_string.clone(),
+            (None, []) => self.ident_as_str(type_properties).into(),
+            (None, [serialize]) => serialize.clone(),
+            (None, serialize) => {
+                // The longest `serialize` value is preferred, so we need to know all of them.
+                let lits = serialize
+                    .iter()
+                    .map(|value| {
+                        value.require_lit(
+                            "Choosing between multiple strum(serialize) values without a strum(to_string)",
+                        )
+                    })
+                    .collect::<syn::Result<Vec<_>>>()?;
+                lits.into_iter()
+                    .max_by_key(|s| s.value().len())
+                    .cloned()
+                    .expect("serialize values")
+                    .into()
+            }
+        };
 
-        if let Some(suffix) = suffix {
-            output = LitStr::new(&(output.value() + &suffix.value()), output.span());
+        if type_properties.prefix.is_none() && type_properties.suffix.is_none() {
+            return Ok(output);
         }
 
-        output
+        let values: Vec<_> = type_properties
+            .prefix
+            .iter()
+            .chain(Some(&output))
+            .chain(type_properties.suffix.iter())
+            .collect();
+        Ok(StrValue::concat(
+            &values,
+            &type_properties.crate_module_path(),
+            output.span(),
+        ))
     }
 
-    pub fn get_serializations(&self, case_style: Option<CaseStyle>) -> Vec<LitStr> {
+    pub fn get_serializations(&self, type_properties: &StrumTypeProperties) -> Vec<StrValue> {
         let mut attrs = self.serialize.clone();
         if let Some(to_string) = &self.to_string {
             attrs.push(to_string.clone());
         }
 
         if attrs.is_empty() {
-            attrs.push(self.ident_as_str(case_style));
+            attrs.push(self.ident_as_str(type_properties).into());
         }
 
         attrs
@@ -83,8 +128,11 @@ impl HasStrumVariantProperties for Variant {
         let mut disabled_kw = None;
         let mut default_kw = None;
         let mut default_with_kw = None;
+        let mut catch_all_kw = None;
         let mut to_string_kw = None;
         let mut ascii_case_insensitive_kw = None;
+        let mut iter_fields_kw = None;
+        let mut weight_kw = None;
         for meta in self.get_metadata()? {
             match meta {
                 VariantMeta::Message { value, kw } => {
@@ -133,6 +181,9 @@ impl HasStrumVariantProperties for Variant {
                     disabled_kw = Some(kw);
                     output.disabled = Some(kw);
                 }
+                VariantMeta::Skip { targets, .. } => {
+                    output.skip.extend(targets);
+                }
                 VariantMeta::Default(kw) => {
                     if let Some(fst_kw) = default_kw {
                         return Err(occurrence_error(fst_kw, kw, "default"));
@@ -141,6 +192,14 @@ impl HasStrumVariantProperties for Variant {
                     default_kw = Some(kw);
                     output.default = Some(kw);
                 }
+                VariantMeta::CatchAll(kw) => {
+                    if let Some(fst_kw) = catch_all_kw {
+                        return Err(occurrence_error(fst_kw, kw, "catch_all"));
+                    }
+
+                    catch_all_kw = Some(kw);
+                    output.catch_all = Some(kw);
+                }
                 VariantMeta::DefaultWith { kw, value } => {
                     if let Some(fst_kw) = default_with_kw {
                         return Err(occurrence_error(fst_kw, kw, "default_with"));
@@ -160,6 +219,22 @@ impl HasStrumVariantProperties for Variant {
                 VariantMeta::Props { props, .. } => {
                     output.props.extend(props);
                 }
+                VariantMeta::IterFields(kw) => {
+                    if let Some(fst_kw) = iter_fields_kw {
+                        return Err(occurrence_error(fst_kw, kw, "iter_fields"));
+                    }
+
+                    iter_fields_kw = Some(kw);
+                    output.iter_fields = Some(kw);
+                }
+                VariantMeta::Weight { kw, value } => {
+                    if let Some(fst_kw) = weight_kw {
+                        return Err(occurrence_error(fst_kw, kw, "weight"));
+                    }
+
+                    weight_kw = Some(kw);
+                    output.weight = Some(value.base10_parse()?);
+                }
             }
         }
 
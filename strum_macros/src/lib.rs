@@ -42,7 +42,9 @@ fn debug_print_generated(ast: &DeriveInput, toks: &TokenStream) {
 ///
 /// The `default` attribute can be applied to a tuple variant with a single data parameter. When a
 /// match isn't found, the given variant will be returned and the input string will be captured in
-/// the parameter.
+/// the parameter. The `catch_all` attribute is similar, but parses the input string into the
+/// parameter with `FromStr` and still fails if that fails, so that the variant can also hold
+/// unknown discriminants with `FromRepr`.
 ///
 /// Note that the implementation of `FromStr` by default only matches on the name of the
 /// variant. There is an option to match on different case conversions through the
@@ -293,6 +295,71 @@ pub fn static_variants_array(input: proc_macro::TokenStream) -> proc_macro::Toke
     toks.into()
 }
 
+/// Implements `gratte::VariantIterator`, which iterates over the unit variants of an enum.
+///
+/// Unlike [`EnumIter`](derive.EnumIter.html), this macro never has to build a variant's fields,
+/// so it works on enums whose fields don't implement `Default`: variants with fields are simply
+/// not yielded, even if their fields do implement `Default`. Variants marked with
+/// `#[strum(disabled)]` or `#[strum(skip(iter))]` are also skipped.
+///
+/// ```
+/// use gratte::VariantIterator;
+///
+/// struct NotDefault(u32);
+///
+/// #[derive(VariantIterator)]
+/// enum Event {
+///     Start,
+///     Data(NotDefault),
+///     Stop,
+/// }
+///
+/// let events: Vec<Event> = Event::iter().collect();
+/// assert!(matches!(events[..], [Event::Start, Event::Stop]));
+/// ```
+#[proc_macro_derive(VariantIterator, attributes(strum))]
+pub fn variant_iterator(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    let toks = macros::variant_iterator::variant_iterator_inner(&ast)
+        .unwrap_or_else(|err| err.to_compile_error());
+    debug_print_generated(&ast, &toks);
+    toks.into()
+}
+
+/// Implements `gratte::VariantMetadata`, which gives the number and names of the variants of an
+/// enum, as well as the name of any variant.
+///
+/// The names are the same as those produced by [`AsRefStr`](derive.AsRefStr.html) and listed by
+/// [`VariantNames`](derive.VariantNames.html). Variants marked with `#[strum(disabled)]` or
+/// `#[strum(skip(names))]` are not counted or listed, but `variant_name` still returns their name.
+///
+/// ```
+/// use gratte::VariantMetadata;
+///
+/// #[derive(VariantMetadata)]
+/// #[strum(serialize_all = "snake_case")]
+/// enum Event {
+///     Start,
+///     #[strum(serialize = "payload")]
+///     Data(Vec<u8>),
+///     Stop,
+/// }
+///
+/// assert_eq!(3, Event::VARIANT_COUNT);
+/// assert_eq!(["start", "payload", "stop"], Event::VARIANT_NAMES);
+/// assert_eq!("payload", Event::Data(vec![1, 2]).variant_name());
+/// ```
+#[proc_macro_derive(VariantMetadata, attributes(strum))]
+pub fn variant_metadata(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    let toks = macros::variant_metadata::variant_metadata_inner(&ast)
+        .unwrap_or_else(|err| err.to_compile_error());
+    debug_print_generated(&ast, &toks);
+    toks.into()
+}
+
 #[proc_macro_derive(AsStaticStr, attributes(strum))]
 #[doc(hidden)]
 #[deprecated(
@@ -470,10 +537,12 @@ pub fn display(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 /// Creates a new type that iterates over the variants of an enum.
 ///
-/// Iterate over the variants of an Enum. Any additional data on your variants will be set to `Default::default()`.
+/// Iterate over the variants of an Enum. Any additional data on your variants will be set to `Default::default()`,
+/// unless the variant is marked with `#[strum(iter_fields)]`, in which case every combination of field values is yielded.
 /// The macro implements [`gratte::IntoEnumIterator`](https://docs.rs/gratte/latest/gratte/trait.IntoEnumIterator.html) on your enum and creates a new type called `YourEnumIter` that is the iterator object.
-/// You cannot derive `EnumIter` on any type with a lifetime bound (`<'a>`) because the iterator would surely
-/// create [unbounded lifetimes](https://doc.rust-lang.org/nightly/nomicon/unbounded-lifetimes.html).
+/// Enums with lifetimes, type parameters or const generics are supported. The iterator is covariant
+/// in them like the enum, and fields that depend on type or const parameters must implement `Default`
+/// (or `IntoEnumIterator` with `iter_fields`) for the iterator to be available.
 ///
 /// ```
 /// // You need to bring the trait into scope to use it!
@@ -500,6 +569,58 @@ pub fn display(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// assert_eq!(Some(Color::Yellow), ci.next());
 /// assert_eq!(None, ci.next());
 /// ```
+///
+/// The macro also adds `iter_from` and `iter_range` functions to the enum, which return the same
+/// iterator type restricted to the variants starting at a given variant or within a range. Fields
+/// of the variants used as bounds are ignored.
+///
+/// ```
+/// use gratte::EnumIter;
+///
+/// #[derive(EnumIter, Debug, PartialEq)]
+/// enum Level {
+///     Trace,
+///     Debug,
+///     Warning,
+///     Error,
+/// }
+///
+/// assert_eq!(vec![Level::Warning, Level::Error], Level::iter_from(Level::Warning).collect::<Vec<_>>());
+/// assert_eq!(vec![Level::Debug, Level::Warning], Level::iter_range(Level::Debug..=Level::Warning).collect::<Vec<_>>());
+/// assert_eq!(1, Level::iter_range(..Level::Debug).len());
+/// ```
+///
+/// It also adds an `iter_with_names` function yielding each variant along with its name, as listed
+/// by [`VariantNames`](derive.VariantNames.html), or `None` for variants skipped for `names`. The
+/// function is left out if some name isn't a string literal. Similarly,
+/// `IntoEnumIterator::iter_with_messages` and `IntoEnumIterator::iter_with_props` yield each
+/// variant along with its message or one of its properties, if any, when
+/// [`EnumMessage`](derive.EnumMessage.html) or [`EnumProperty`](derive.EnumProperty.html) are
+/// derived too. All of them yield the same variants as `iter`, so disabled variants are skipped.
+///
+/// ```
+/// use gratte::{EnumIter, EnumMessage, IntoEnumIterator};
+///
+/// #[derive(EnumIter, EnumMessage, Debug, PartialEq)]
+/// #[strum(serialize_all = "lowercase")]
+/// enum Level {
+///     #[strum(message = "Everything")]
+///     Trace,
+///     #[strum(disabled)]
+///     Debug,
+///     #[strum(serialize = "warn")]
+///     Warning,
+/// }
+///
+/// assert_eq!(
+///     vec![(Level::Trace, Some("trace")), (Level::Warning, Some("warn"))],
+///     Level::iter_with_names().collect::<Vec<_>>(),
+/// );
+/// assert_eq!(
+///     vec![(Level::Trace, Some("Everything")), (Level::Warning, None)],
+///     Level::iter_with_messages().collect::<Vec<_>>(),
+/// );
+/// ```
 #[proc_macro_derive(EnumIter, attributes(strum))]
 pub fn enum_iter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse_macro_input!(input as DeriveInput);
@@ -510,6 +631,127 @@ pub fn enum_iter(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     toks.into()
 }
 
+/// Generates `next()`, `prev()`, `next_wrapping()` and `prev_wrapping()` methods to navigate
+/// between the variants of an enum in declaration order.
+///
+/// The variants follow the same order as [`EnumIter`](derive.EnumIter.html) and
+/// [`EnumIndex`](derive.EnumIndex.html). Variants marked with `#[strum(disabled)]`,
+/// `#[strum(skip(iter))]` or `#[strum(catch_all)]` are skipped: they are never returned, but
+/// calling a method on them returns the closest enabled variant. Like with `EnumIter`, the fields
+/// of the variants returned are set to `Default::default()`. All methods take `self` by value, and
+/// are `const fn`s if the enum only has unit variants.
+///
+/// ```
+/// use gratte::EnumCycle;
+///
+/// #[derive(EnumCycle, Debug, PartialEq)]
+/// enum Light {
+///     Green,
+///     Yellow,
+///     #[strum(disabled)]
+///     Blinking,
+///     Red,
+/// }
+///
+/// assert_eq!(Some(Light::Yellow), Light::Green.next());
+/// assert_eq!(Some(Light::Red), Light::Yellow.next());
+/// assert_eq!(None, Light::Red.next());
+/// assert_eq!(Light::Green, Light::Red.next_wrapping());
+/// assert_eq!(None, Light::Green.prev());
+/// assert_eq!(Light::Red, Light::Green.prev_wrapping());
+/// ```
+#[proc_macro_derive(EnumCycle, attributes(strum))]
+pub fn enum_cycle(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    let toks =
+        macros::enum_cycle::enum_cycle_inner(&ast).unwrap_or_else(|err| err.to_compile_error());
+    debug_print_generated(&ast, &toks);
+    toks.into()
+}
+
+/// Maps the variants of an enum to dense indices, from `0` to the number of variants (exclusive).
+///
+/// Implements `gratte::EnumIndex` and adds a `COUNT` constant, `const fn index(&self)`,
+/// `const fn try_index(&self)` and `fn from_index(usize)` to the enum. `COUNT` is the number of
+/// indexed variants, the same as with [`EnumCount`](derive.EnumCount.html), and the constant added
+/// to the enum spares you from disambiguating between the two traits. Indices follow declaration
+/// order and are independent of the enum's discriminants, so they are suitable for array-backed
+/// storage or bitsets. Variants marked with
+/// `#[strum(disabled)]`, `#[strum(skip(iter))]` or `#[strum(catch_all)]` have no index: calling
+/// `index()` on them panics, while `try_index()` returns `None`. Like with
+/// [`EnumIter`](derive.EnumIter.html), the fields of variants returned by `from_index` are set to
+/// `Default::default()`; `from_index` is only a `const fn` if every indexed variant is a unit
+/// variant.
+///
+/// ```
+/// use gratte::EnumIndex;
+///
+/// #[derive(EnumIndex, Debug, PartialEq)]
+/// enum Level {
+///     Debug = 10,
+///     Info = 20,
+///     Warning = 30,
+/// }
+///
+/// const WARNING: usize = Level::Warning.index();
+/// assert_eq!(2, WARNING);
+/// assert_eq!(Some(Level::Info), Level::from_index(1));
+/// assert_eq!(None, Level::from_index(3));
+/// assert_eq!(Some(0), Level::Debug.try_index());
+/// assert_eq!(3, Level::COUNT);
+/// ```
+#[proc_macro_derive(EnumIndex, attributes(strum))]
+pub fn enum_index(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    let toks =
+        macros::enum_index::enum_index_inner(&ast).unwrap_or_else(|err| err.to_compile_error());
+    debug_print_generated(&ast, &toks);
+    toks.into()
+}
+
+/// Lets the enum be used as the key of a `gratte::EnumMap`.
+///
+/// Implements `gratte::EnumArray<V>` for every `V`, with an array of `V` for each variant that has
+/// an index. The indices are given by `gratte::EnumIndex`, which must be derived too. Unlike
+/// [`EnumTable`](derive.EnumTable.html), which generates a new type for each enum, `EnumMap<K, V>`
+/// is a single generic type, so it can be used in generic code.
+///
+/// ```
+/// use gratte::{EnumArray, EnumIndex, EnumMap};
+///
+/// #[derive(EnumIndex, EnumArray, Debug, Clone, Copy, PartialEq)]
+/// enum Color {
+///     Red,
+///     Green,
+///     #[strum(disabled)]
+///     Teal,
+///     Blue,
+/// }
+///
+/// fn describe<K: EnumArray<u8> + core::fmt::Debug>(map: &EnumMap<K, u8>) -> String {
+///     map.iter()
+///         .map(|(key, value)| format!("{:?}={}", key, value))
+///         .collect::<Vec<_>>()
+///         .join(",")
+/// }
+///
+/// let mut map: EnumMap<Color, u8> = EnumMap::default();
+/// map[Color::Green] = 2;
+/// assert_eq!(describe(&map), "Red=0,Green=2,Blue=0");
+/// assert_eq!(map.into_array(), [0, 2, 0]);
+/// ```
+#[proc_macro_derive(EnumArray, attributes(strum))]
+pub fn enum_array(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    let toks =
+        macros::enum_array::enum_array_inner(&ast).unwrap_or_else(|err| err.to_compile_error());
+    debug_print_generated(&ast, &toks);
+    toks.into()
+}
+
 /// Generated `is_*()` methods for each variant.
 /// E.g. `Color.is_red()`.
 ///
@@ -571,16 +813,32 @@ pub fn enum_try_as(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 /// Creates a new type that maps all the variants of an enum to another generic value.
 ///
-/// This macro only supports enums with unit type variants.A new type called `YourEnumTable<T>`. Essentially, it's a wrapper
-/// `[T; YourEnum::Count]` where gets/sets are infallible. Some important caveats to note:
+/// This macro creates a new type called `YourEnumTable<T>`. Essentially, it's a wrapper
+/// `[T; YourEnum::Count]` where gets/sets are infallible. The values are stored in an array, in declaration order,
+/// which can be accessed with `as_slice` and `as_mut_slice`, and tables can be built in `const` contexts with
+/// `new` and `from_array`. Some important caveats to note:
 ///
 /// * The size of `YourEnumTable<T>` increases with the number of variants, not the number of values because it's always
 ///   fully populated. This means it may not be a good choice for sparsely populated maps.
 ///
 /// * Lookups are basically constant time since it's functionally an array index.
 ///
-/// * Your variants cannot have associated data. You can use `EnumDiscriminants` to generate an Enum with the same
-///   names to work around this.
+/// * If your variants have associated data, the table is keyed by a generated `YourEnumTableKey` enum with a unit
+///   variant for each of yours: `from_closure`, `keys`, `iter` and so on give keys rather than values of your enum.
+///   The table can be indexed with either a key or a reference to a value of your enum, which maps to its variant,
+///   and so can `get`, `get_mut`, `replace` and `try_set` be called. `YourEnumTableKey` can be converted from a
+///   reference to a value.
+///
+/// * If your enum has generic parameters, the table takes the same parameters followed by the type of its
+///   values, as in `YourEnumTable<'a, U, T>`.
+///
+/// * With the `serde` feature and the `#[strum(table_serde)]` attribute, the table implements `Serialize` and
+///   `Deserialize` as a map from the names of the variants, as produced by `Display`, to their values. See the
+///   [additional attributes](https://docs.rs/gratte/latest/gratte/additional_attributes/index.html).
+///
+/// * Tables can be combined with `map`, `zip` and `zip_with`, and summarized with `fold`, `any`, `every` and
+///   `max_by_key`. Tables also implement `Add`, `Sub`, `AddAssign` and `SubAssign` element-wise when their values
+///   do.
 ///
 /// # Stability
 ///
@@ -612,6 +870,46 @@ pub fn enum_try_as(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 /// complex_map[Color::Green] = complex_map[Color::Red];
 /// assert_eq!(complex_map, ColorTable::new(0, 3, 0, 3));
+///
+/// // The table can be iterated over, skipping disabled variants, and collected into.
+/// assert_eq!(complex_map.values().sum::<i32>(), 6);
+/// assert_eq!(complex_map.iter().filter(|(_, &value)| value == 0).count(), 2);
+/// let collected: ColorTable<i32> = [(Color::Blue, 1)].into_iter().collect();
+/// assert_eq!(collected, ColorTable::new(0, 0, 0, 1));
+///
+/// // Tables are backed by arrays, so they can be built in constants and viewed as slices.
+/// static COSTS: ColorTable<u32> = ColorTable::from_array([4, 3, 2, 1]);
+/// assert_eq!(COSTS[Color::Yellow], 3);
+/// assert_eq!(COSTS.as_slice(), &[4, 3, 2, 1]);
+///
+/// // Tables can be combined element-wise, and summarized.
+/// let totals = ColorTable::new(1, 2, 3, 4) + ColorTable::filled(10);
+/// assert_eq!(totals, ColorTable::new(11, 12, 13, 14));
+/// let labelled = totals.zip(ColorTable::new("a", "b", "c", "d")).map(|_, (n, s)| format!("{s}{n}"));
+/// assert_eq!(labelled[Color::Green], "c13");
+/// assert!(matches!(COSTS.max_by_key(|&cost| cost), Color::Red));
+/// assert!(COSTS.every(|_, &cost| cost > 0));
+/// assert_eq!(COSTS.clone().fold(0, |acc, _, cost| acc + cost), 10);
+/// ```
+///
+/// With data-carrying variants:
+///
+/// ```rust
+/// use gratte::EnumTable;
+///
+/// #[derive(EnumTable)]
+/// enum Event {
+///     Click { x: i32, y: i32 },
+///     Key(char),
+/// }
+///
+/// let mut counts = EventTable::filled(0);
+/// for event in &[Event::Key('a'), Event::Click { x: 1, y: 2 }, Event::Key('b')] {
+///     counts[event] += 1;
+/// }
+/// assert_eq!(counts[EventTableKey::Key], 2);
+/// assert_eq!(counts.keys().next(), Some(EventTableKey::Click));
+/// assert_eq!(EventTableKey::from(&Event::Key('c')), EventTableKey::Key);
 /// ```
 #[doc(hidden)]
 #[proc_macro_derive(EnumTable, attributes(strum))]
@@ -624,6 +922,60 @@ pub fn enum_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     toks.into()
 }
 
+/// Creates a compact set of the variants of an enum.
+///
+/// This macro creates a new type called `YourEnumSet`, which stores each variant as a bit of the
+/// smallest unsigned integer that has enough bits for every variant, up to `u128`. The bit of a
+/// variant is its index as given by [`EnumIndex`](derive.EnumIndex.html), in declaration order,
+/// and variants marked with `#[strum(disabled)]` or `#[strum(skip(iter))]` can't be stored in
+/// the set. Only enums with unit variants are supported.
+///
+/// The set is `Copy`, and `const fn`s are provided to build and query it: `new`, `all`, `insert`,
+/// `remove`, `contains`, `union`, `intersection`, `difference`, `symmetric_difference`,
+/// `complement`, `is_subset`, `is_superset`, `is_disjoint`, `is_empty` and `len`. Since
+/// `&mut` references can't be used in `const fn`s, `insert` and `remove` return a new set; the
+/// operators `|`, `&`, `^`, `-` and `!` and their assigning forms are also implemented. Sets can
+/// be iterated over in declaration order, collected from variants and converted from a single
+/// variant.
+///
+/// Sets implement `Display` and `FromStr` as lists of variant names separated by `|`, using the
+/// same names as `Display` and `EnumString`, including `to_string`, `serialize`,
+/// `serialize_all` and `ascii_case_insensitive`. Parsing ignores whitespace around names and
+/// fails with `gratte::ParseError::VariantNotFound` on an unknown name. Unlike with `Display`
+/// and `EnumString`, variants skipped for `display` or `parse` are still written and parsed, so
+/// that every set can be parsed back from its string.
+///
+/// ```
+/// use gratte::EnumSet;
+///
+/// #[derive(EnumSet, Debug, Clone, Copy, PartialEq)]
+/// #[strum(serialize_all = "lowercase")]
+/// enum Perm {
+///     Read,
+///     Write,
+///     Execute,
+/// }
+///
+/// const READ_WRITE: PermSet = PermSet::new().insert(Perm::Read).insert(Perm::Write);
+/// assert!(READ_WRITE.contains(Perm::Write));
+/// assert!(!READ_WRITE.contains(Perm::Execute));
+/// assert_eq!(READ_WRITE.complement(), PermSet::from(Perm::Execute));
+/// assert_eq!(READ_WRITE.bits(), 0b011);
+/// assert_eq!(READ_WRITE.iter().collect::<Vec<_>>(), vec![Perm::Read, Perm::Write]);
+///
+/// assert_eq!(READ_WRITE.to_string(), "read|write");
+/// assert_eq!("write | read".parse::<PermSet>(), Ok(READ_WRITE));
+/// assert!("read|delete".parse::<PermSet>().is_err());
+/// ```
+#[proc_macro_derive(EnumSet, attributes(strum))]
+pub fn enum_set(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = syn::parse_macro_input!(input as DeriveInput);
+
+    let toks = macros::enum_set::enum_set_inner(&ast).unwrap_or_else(|err| err.to_compile_error());
+    debug_print_generated(&ast, &toks);
+    toks.into()
+}
+
 /// Add a function to enum that allows accessing variants by its discriminant
 ///
 /// This macro adds a standalone function to obtain an enum variant by its discriminant. The macro adds
@@ -639,8 +991,22 @@ pub fn enum_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// to allow `match` statements in `const fn`. The no additional data requirement is due to the
 /// inability to use `Default::default()` in a `const fn`.
 ///
-/// You cannot derive `FromRepr` on any type with a lifetime bound (`<'a>`) because the function would surely
-/// create [unbounded lifetimes](https://doc.rust-lang.org/nightly/nomicon/unbounded-lifetimes.html).
+/// Enums with lifetimes, type parameters or const generics are supported. `from_repr` is only
+/// available when the fields that depend on type or const parameters implement `Default`.
+///
+/// The macro also implements `TryFrom` of the discriminant type, which calls `from_repr` and fails
+/// with a `gratte::FromReprError` holding the rejected value, and adds a `const fn to_repr(&self)`
+/// returning the discriminant of any variant, including those with data or skipped with
+/// `#[strum(skip(repr))]`. Skipped variants still count when computing the discriminants of the
+/// following variants. For enums without data, `From<YourEnum>` is implemented for the
+/// discriminant type too. Both functions are also available to generic code through the
+/// `gratte::FromRepr` trait.
+///
+/// A single-field variant marked with `#[strum(catch_all)]` receives the discriminants that don't
+/// match any other variant, so `from_repr` always returns `Some` and `From` of the discriminant type
+/// is implemented instead of `TryFrom`. Its `to_repr` returns the stored value, so `from_repr` only
+/// gives the variant back if that value isn't the discriminant of another variant. See the
+/// [additional attributes](https://docs.rs/gratte/latest/gratte/additional_attributes/index.html).
 ///
 /// ```
 /// use gratte::FromRepr;
@@ -668,6 +1034,13 @@ pub fn enum_table(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// }
 ///
 /// assert_eq!(None, Vehicle::from_repr(0));
+///
+/// // Conversions in both directions
+/// assert_eq!(Ok(Vehicle::Truck), Vehicle::try_from(3));
+/// assert_eq!(Err(gratte::FromReprError(2)), Vehicle::try_from(2));
+/// assert_eq!(3, Vehicle::Truck.to_repr());
+/// assert_eq!(1, u8::from(Vehicle::Car));
+/// assert_eq!(1, Color::Green { range: 5 }.to_repr());
 /// ```
 ///
 /// On versions of rust >= 1.46, the `from_repr` function is marked `const`.
@@ -0,0 +1,43 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Data, DeriveInput};
+
+use crate::helpers::{dense_variants, fresh_param, non_enum_error, HasTypeProperties};
+
+pub fn enum_array_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let gen = &ast.generics;
+    let (_, ty_generics, where_clause) = gen.split_for_impl();
+
+    let variants = match &ast.data {
+        Data::Enum(v) => &v.variants,
+        _ => return Err(non_enum_error()),
+    };
+
+    let type_properties = ast.get_type_properties()?;
+    let strum_module_path = type_properties.crate_module_path();
+
+    // The array has an element for each variant indexed by `EnumIndex`.
+    let count = dense_variants(variants)?
+        .iter()
+        .filter(|variant| variant.index.is_some())
+        .count();
+
+    let v = fresh_param(gen, "V");
+    let f = fresh_param(gen, "F");
+    let mut array_generics = gen.clone();
+    array_generics.params.push(parse_quote!(#v));
+    let (impl_generics, _, _) = array_generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #strum_module_path::EnumArray<#v> for #name #ty_generics #where_clause {
+            type Array = [#v; #count];
+
+            #[inline]
+            fn array_from_fn<#f: ::core::ops::FnMut(usize) -> #v>(func: #f) -> [#v; #count] {
+                ::core::array::from_fn(func)
+            }
+        }
+    })
+}
@@ -2,18 +2,14 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput};
 
-use crate::helpers::variant_props::HasStrumVariantProperties;
-use crate::helpers::{non_enum_error, HasTypeProperties};
+use crate::helpers::{dense_variants, non_enum_error, HasTypeProperties};
 
 pub(crate) fn enum_count_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let n = match &ast.data {
-        Data::Enum(v) => v.variants.iter().try_fold(0usize, |acc, v| {
-            if v.get_variant_properties()?.disabled.is_none() {
-                Ok::<usize, syn::Error>(acc + 1usize)
-            } else {
-                Ok::<usize, syn::Error>(acc)
-            }
-        })?,
+        Data::Enum(v) => dense_variants(&v.variants)?
+            .iter()
+            .filter(|variant| variant.index.is_some())
+            .count(),
         _ => return Err(non_enum_error()),
     };
     let type_properties = ast.get_type_properties()?;
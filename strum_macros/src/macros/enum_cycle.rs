@@ -0,0 +1,149 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, Ident, Variant};
+
+use crate::helpers::{default_fields, dense_variants, generic_bounds, non_enum_error};
+
+pub fn enum_cycle_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let gen = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = gen.split_for_impl();
+
+    let variants = match &ast.data {
+        Data::Enum(v) => &v.variants,
+        _ => return Err(non_enum_error()),
+    };
+
+    // Every variant is listed along with whether it is part of the cycle; skipped variants
+    // still need to go somewhere when `next` or `prev` is called on them.
+    let variants: Vec<(&Variant, bool)> = dense_variants(variants)?
+        .into_iter()
+        .map(|dense| (dense.variant, dense.index.is_some()))
+        .collect();
+
+    let first = variants
+        .iter()
+        .find(|(_, enabled)| *enabled)
+        .map(|(v, _)| *v);
+    let last = variants
+        .iter()
+        .rev()
+        .find(|(_, enabled)| *enabled)
+        .map(|(v, _)| *v);
+
+    let mut next_arms = Vec::new();
+    let mut prev_arms = Vec::new();
+    let mut next_wrapping_arms = Vec::new();
+    let mut prev_wrapping_arms = Vec::new();
+    for (idx, (variant, _)) in variants.iter().enumerate() {
+        let ident = &variant.ident;
+        let next = variants[idx + 1..]
+            .iter()
+            .find(|(_, enabled)| *enabled)
+            .map(|(v, _)| *v);
+        let prev = variants[..idx]
+            .iter()
+            .rev()
+            .find(|(_, enabled)| *enabled)
+            .map(|(v, _)| *v);
+
+        next_arms.push(option_arm(name, ident, next));
+        prev_arms.push(option_arm(name, ident, prev));
+
+        // If no variant is part of the cycle, the wrapping functions have nowhere to go.
+        next_wrapping_arms.push(wrapping_arm(name, ident, next.or(first)));
+        prev_wrapping_arms.push(wrapping_arm(name, ident, prev.or(last)));
+    }
+
+    // The variants returned are built with `Default::default()` fields, which can't be called in
+    // a `const` context. Values with fields may also need to be dropped, which `const fn`s can't
+    // always do.
+    let all_unit = variants
+        .iter()
+        .all(|(variant, _)| matches!(variant.fields, Fields::Unit));
+    let constness = if all_unit {
+        quote! { const }
+    } else {
+        quote! {}
+    };
+    let default_bounds = generic_bounds(
+        variants
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .flat_map(|(variant, _)| variant.fields.iter().map(|field| &field.ty)),
+        gen,
+        &quote! { ::core::default::Default },
+    );
+    let fn_where_clause = if default_bounds.is_empty() {
+        quote! {}
+    } else {
+        quote! { where #(#default_bounds),* }
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the variant following this one in declaration order, or `None` if this
+            /// is the last variant.
+            #[must_use]
+            #[inline]
+            pub #constness fn next(self) -> ::core::option::Option<Self> #fn_where_clause {
+                match self {
+                    #(#next_arms),*
+                }
+            }
+
+            /// Returns the variant preceding this one in declaration order, or `None` if this
+            /// is the first variant.
+            #[must_use]
+            #[inline]
+            pub #constness fn prev(self) -> ::core::option::Option<Self> #fn_where_clause {
+                match self {
+                    #(#prev_arms),*
+                }
+            }
+
+            /// Returns the variant following this one in declaration order, wrapping around
+            /// to the first variant after the last one.
+            #[must_use]
+            #[inline]
+            pub #constness fn next_wrapping(self) -> Self #fn_where_clause {
+                match self {
+                    #(#next_wrapping_arms),*
+                }
+            }
+
+            /// Returns the variant preceding this one in declaration order, wrapping around
+            /// to the last variant before the first one.
+            #[must_use]
+            #[inline]
+            pub #constness fn prev_wrapping(self) -> Self #fn_where_clause {
+                match self {
+                    #(#prev_wrapping_arms),*
+                }
+            }
+        }
+    })
+}
+
+fn option_arm(name: &Ident, ident: &Ident, target: Option<&Variant>) -> TokenStream {
+    match target {
+        Some(target) => {
+            let target_ident = &target.ident;
+            let params = default_fields(&target.fields);
+            quote! { #name::#ident { .. } => ::core::option::Option::Some(#name::#target_ident #params) }
+        }
+        None => quote! { #name::#ident { .. } => ::core::option::Option::None },
+    }
+}
+
+fn wrapping_arm(name: &Ident, ident: &Ident, target: Option<&Variant>) -> TokenStream {
+    match target {
+        Some(target) => {
+            let target_ident = &target.ident;
+            let params = default_fields(&target.fields);
+            quote! { #name::#ident { .. } => #name::#target_ident #params }
+        }
+        None => quote! { #name::#ident { .. } => self },
+    }
+}
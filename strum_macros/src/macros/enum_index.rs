@@ -0,0 +1,133 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+use crate::helpers::{
+    default_fields, dense_variants, generic_bounds, non_enum_error, HasTypeProperties,
+};
+
+pub fn enum_index_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let gen = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = gen.split_for_impl();
+
+    let variants = match &ast.data {
+        Data::Enum(v) => &v.variants,
+        _ => return Err(non_enum_error()),
+    };
+
+    let type_properties = ast.get_type_properties()?;
+    let strum_module_path = type_properties.crate_module_path();
+
+    let mut index_arms = Vec::new();
+    let mut try_index_arms = Vec::new();
+    let mut from_index_arms = Vec::new();
+    let mut field_types = Vec::new();
+    let mut all_unit = true;
+    for dense in dense_variants(variants)? {
+        let (variant, ident) = (dense.variant, &dense.variant.ident);
+        let idx = match dense.index {
+            Some(idx) => idx,
+            None => {
+                let message = format!("{}::{} has no index", name, ident);
+                index_arms.push(quote! { #name::#ident { .. } => ::core::panic!(#message) });
+                try_index_arms
+                    .push(quote! { #name::#ident { .. } => ::core::option::Option::None });
+                continue;
+            }
+        };
+
+        let params = default_fields(&variant.fields);
+        all_unit &= matches!(variant.fields, Fields::Unit);
+        field_types.extend(variant.fields.iter().map(|field| &field.ty));
+
+        index_arms.push(quote! { #name::#ident { .. } => #idx });
+        try_index_arms.push(quote! { #name::#ident { .. } => ::core::option::Option::Some(#idx) });
+        from_index_arms
+            .push(quote! { #idx => ::core::option::Option::Some(#name::#ident #params) });
+    }
+
+    let count = from_index_arms.len();
+    from_index_arms.push(quote! { _ => ::core::option::Option::None });
+
+    // `Default::default()` can't be called in a `const` context.
+    let from_index_constness = if all_unit {
+        quote! { const }
+    } else {
+        quote! {}
+    };
+
+    // Only `from_index` needs the fields to implement `Default`, so the bounds are put on it
+    // rather than on the inherent impl.
+    let default_bounds = generic_bounds(field_types, gen, &quote! { ::core::default::Default });
+    let fn_where_clause = if default_bounds.is_empty() {
+        quote! {}
+    } else {
+        quote! { where #(#default_bounds),* }
+    };
+    let mut trait_generics = gen.clone();
+    trait_generics
+        .make_where_clause()
+        .predicates
+        .extend(default_bounds);
+    let trait_where_clause = &trait_generics.where_clause;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// The number of variants that have an index.
+            pub const COUNT: usize = #count;
+
+            /// Returns the index of this variant, from `0` to the number of variants (exclusive).
+            ///
+            /// # Panics
+            ///
+            /// If the variant is skipped for `iter` and has no index.
+            #[must_use]
+            #[inline]
+            pub const fn index(&self) -> usize {
+                match self {
+                    #(#index_arms),*
+                }
+            }
+
+            /// Returns the index of this variant, or `None` if it is skipped for `iter`.
+            #[must_use]
+            #[inline]
+            pub const fn try_index(&self) -> ::core::option::Option<usize> {
+                match self {
+                    #(#try_index_arms),*
+                }
+            }
+
+            /// Returns the variant at the given index, or `None` if the index is out of bounds.
+            #[must_use]
+            #[inline]
+            pub #from_index_constness fn from_index(index: usize) -> ::core::option::Option<Self> #fn_where_clause {
+                match index {
+                    #(#from_index_arms),*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #strum_module_path::EnumIndex for #name #ty_generics #trait_where_clause {
+            const COUNT: usize = #count;
+
+            #[inline]
+            fn index(&self) -> usize {
+                <#name #ty_generics>::index(self)
+            }
+
+            #[inline]
+            fn try_index(&self) -> ::core::option::Option<usize> {
+                <#name #ty_generics>::try_index(self)
+            }
+
+            #[inline]
+            fn from_index(index: usize) -> ::core::option::Option<Self> {
+                <#name #ty_generics>::from_index(index)
+            }
+        }
+    })
+}
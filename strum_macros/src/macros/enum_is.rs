@@ -1,4 +1,4 @@
-use crate::helpers::{case_style::snakify, non_enum_error, HasStrumVariantProperties};
+use crate::helpers::{case_style::snakify, non_enum_error, HasStrumVariantProperties, SkipTarget};
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{Data, DeriveInput};
@@ -14,7 +14,11 @@ pub fn enum_is_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let variants: Vec<_> = variants
         .iter()
         .filter_map(|variant| {
-            if variant.get_variant_properties().ok()?.disabled.is_some() {
+            if variant
+                .get_variant_properties()
+                .ok()?
+                .is_skipped(SkipTarget::Is)
+            {
                 return None;
             }
 
@@ -1,69 +1,285 @@
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Data, DeriveInput, Fields, Ident};
+use syn::{Data, DeriveInput, Fields, Generics, Ident, Path, Type, Variant};
 
-use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties};
+use crate::helpers::{
+    add_weight, default_fields, dense_variants, generic_bounds, non_enum_error, zero_weight_error,
+    DenseVariant, HasInnerVariantProperties, HasTypeProperties, SkipTarget,
+};
 
 pub fn enum_iter_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
     let gen = &ast.generics;
-    let (impl_generics, ty_generics, where_clause) = gen.split_for_impl();
+    let (struct_generics, ty_generics, _) = gen.split_for_impl();
     let vis = &ast.vis;
     let type_properties = ast.get_type_properties()?;
     let strum_module_path = type_properties.crate_module_path();
     let doc_comment = format!("An iterator over the variants of [{}]", name);
 
-    if gen.lifetimes().count() > 0 {
-        return Err(syn::Error::new(
-            Span::call_site(),
-            "This macro doesn't support enums with lifetimes. \
-             The resulting enums would be unbounded.",
-        ));
-    }
-
-    let phantom_data = if gen.type_params().count() > 0 {
-        let g = gen.type_params().map(|param| &param.ident);
-        quote! { < fn() -> ( #(#g),* ) > }
-    } else {
-        quote! { < fn() -> () > }
-    };
+    // The iterator only ever creates values of the enum, so it is covariant in the lifetimes
+    // and type parameters, like the enum itself.
+    let lifetimes = gen.lifetimes().map(|param| &param.lifetime);
+    let types = gen.type_params().map(|param| &param.ident);
+    let phantom_data = quote! { < fn() -> ( #(&#lifetimes (),)* #(#types,)* ) > };
 
     let variants = match &ast.data {
         Data::Enum(v) => &v.variants,
         _ => return Err(non_enum_error()),
     };
 
-    let mut arms = Vec::new();
-    let mut idx = 0usize;
-    for variant in variants {
-        if variant.get_variant_properties()?.disabled.is_some() {
+    let variants = dense_variants(variants)?;
+
+    // Fields are filled with `Default::default()`, except in variants with `iter_fields`.
+    let mut default_types = Vec::new();
+    let mut iter_types = Vec::new();
+    for DenseVariant {
+        variant,
+        properties: variant_properties,
+        index,
+    } in &variants
+    {
+        if index.is_none() {
             continue;
         }
+        if variant_properties.iter_fields.is_some() || type_properties.iter_fields {
+            for field in &variant.fields {
+                if field.get_variant_inner_properties()?.iter_values.is_none()
+                    && !is_bool(&field.ty)
+                {
+                    iter_types.push(&field.ty);
+                }
+            }
+        } else {
+            default_types.extend(variant.fields.iter().map(|field| &field.ty));
+        }
+    }
+    let mut generics = gen.clone();
+    let predicates = &mut generics.make_where_clause().predicates;
+    predicates.extend(generic_bounds(
+        default_types,
+        gen,
+        &quote! { ::core::default::Default },
+    ));
+    predicates.extend(generic_bounds(
+        iter_types,
+        gen,
+        &quote! { #strum_module_path::IntoEnumIterator },
+    ));
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
 
+    let mut arms = Vec::new();
+    let mut blocks = Vec::new();
+    let mut counts = Vec::new();
+    let mut unit_count = 0usize;
+    let mut position_arms = Vec::new();
+    let mut position_blocks = Vec::new();
+    let mut sample_counts = Vec::new();
+    let mut sample_weights = Vec::new();
+    let mut total_weight = 0u32;
+    let mut name_arms = Vec::new();
+    let mut has_names = true;
+    for DenseVariant {
+        variant,
+        properties: variant_properties,
+        index,
+    } in &variants
+    {
         let ident = &variant.ident;
-        let params = match &variant.fields {
-            Fields::Unit => quote! {},
-            Fields::Unnamed(fields) => {
-                let defaults = ::core::iter::repeat(quote!(::core::default::Default::default()))
-                    .take(fields.unnamed.len());
-                quote! { (#(#defaults),*) }
-            }
-            Fields::Named(fields) => {
-                let fields = fields
-                    .named
-                    .iter()
-                    .map(|field| field.ident.as_ref().unwrap());
-                quote! { {#(#fields: ::core::default::Default::default()),*} }
+        if index.is_none() {
+            // Skipped variants are positioned right before the next variant that isn't.
+            position_arms.push(quote! { #name::#ident { .. } => (#unit_count, #unit_count) });
+            position_blocks.push(quote! {
+                if ::core::matches!(value, #name::#ident { .. }) {
+                    return (start, start);
+                }
+            });
+            continue;
+        }
+
+        if !variant_properties.is_skipped(SkipTarget::Names) {
+            match variant_properties.get_preferred_name(&type_properties) {
+                Ok(variant_name) => name_arms.push(quote! {
+                    #name::#ident { .. } => ::core::option::Option::Some(#variant_name)
+                }),
+                Err(_) => has_names = false,
             }
-        };
+        }
+
+        if variant_properties.iter_fields.is_some() || type_properties.iter_fields {
+            let (count, block) = iter_fields_block(name, variant, &strum_module_path)?;
+            sample_counts.push(count.clone());
+            sample_weights.push(variant_properties.weight());
+            total_weight = add_weight(total_weight, variant_properties.weight(), variant)?;
+            position_blocks.push(quote! {
+                let count = #count;
+                if ::core::matches!(value, #name::#ident { .. }) {
+                    return (start, start + count);
+                }
+                start += count;
+            });
+            counts.push(count);
+            blocks.push(block);
+            continue;
+        }
+
+        for field in &variant.fields {
+            if field.get_variant_inner_properties()?.iter_values.is_some() {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "`iter_values` can only be used in variants with `#[strum(iter_fields)]`",
+                ));
+            }
+        }
+
+        let params = default_fields(&variant.fields);
 
+        let idx = unit_count;
         arms.push(quote! {#idx => ::core::option::Option::Some(#name::#ident #params)});
-        idx += 1;
+        position_arms.push(quote! { #name::#ident { .. } => (#idx, #idx + 1) });
+        sample_counts.push(quote! { 1usize });
+        sample_weights.push(variant_properties.weight());
+        total_weight = add_weight(total_weight, variant_properties.weight(), variant)?;
+        position_blocks.push(quote! {
+            if ::core::matches!(value, #name::#ident { .. }) {
+                return (start, start + 1);
+            }
+            start += 1;
+        });
+        blocks.push(quote! {
+            if idx == 0 {
+                return ::core::option::Option::Some(#name::#ident #params);
+            }
+            idx -= 1;
+        });
+        unit_count += 1;
     }
 
-    let variant_count = arms.len();
-    arms.push(quote! { _ => ::core::option::Option::None });
+    // If some variants yield more than one value, we can't map indices to variants
+    // directly, so we go through the variants one by one instead.
+    let (variant_count, get_body, position_body) = if counts.is_empty() {
+        arms.push(quote! { _ => ::core::option::Option::None });
+        (
+            quote! { #unit_count },
+            quote! {
+                match idx {
+                    #(#arms),*
+                }
+            },
+            quote! {
+                match *value {
+                    #(#position_arms),*
+                }
+            },
+        )
+    } else {
+        (
+            quote! { #unit_count #(+ #counts)* },
+            quote! {
+                let mut idx = idx;
+                #(#blocks)*
+                let _ = idx;
+                ::core::option::Option::None
+            },
+            quote! {
+                let mut start = 0usize;
+                #(#position_blocks)*
+                let _ = start;
+                ::core::unreachable!()
+            },
+        )
+    };
     let iter_name = syn::parse_str::<Ident>(&format!("{}Iter", name)).unwrap();
+    // Names can't always be computed, see `get_preferred_name`, in which case `iter_with_names` is
+    // left out. Variants skipped for `names` are yielded without a name, as they aren't listed in
+    // `VARIANTS`.
+    let iter_with_names_fn = if !has_names {
+        TokenStream::new()
+    } else {
+        // Empty enums can't have values to name.
+        let with_name = if variants.is_empty() {
+            quote! { |value| match value {} }
+        } else {
+            quote! {
+                |value| {
+                    #[allow(unreachable_patterns)]
+                    let name = match &value {
+                        #(#name_arms,)*
+                        _ => ::core::option::Option::None,
+                    };
+                    (value, name)
+                }
+            }
+        };
+        quote! {
+            /// Returns an iterator over the variants of this enum along with their names, as
+            /// listed in `VARIANTS` by `VariantNames`, or `None` for variants left out of it.
+            #[must_use]
+            #[allow(clippy::type_complexity)]
+            pub fn iter_with_names() -> ::core::iter::Map<#iter_name #ty_generics, fn(#name #ty_generics) -> (#name #ty_generics, ::core::option::Option<&'static str>)> {
+                <#name #ty_generics as #strum_module_path::IntoEnumIterator>::iter().map(#with_name)
+            }
+        }
+    };
+    let par_iter_impl = par_iter_impl(ast, &generics, &iter_name, &strum_module_path);
+
+    // Variants are sampled by picking a variant according to its weight, then picking one of
+    // the values yielded for that variant uniformly. Variants with `iter_fields` may yield no
+    // values, in which case they are never picked. Enums without variants to sample have no
+    // implementation, and neither do enums whose values are all unit variants: `VariantArray`
+    // implements `Distribution` for them, and both derives can't. The implementation, and the
+    // check that some variant can be picked, are only kept if the `rand` feature of the main crate
+    // is enabled.
+    let rand = quote! { #strum_module_path::_private_rand_reexport_for_macro_if_rand_feature };
+    let has_fields = variants
+        .iter()
+        .any(|dense| dense.index.is_some() && !matches!(dense.variant.fields, Fields::Unit));
+    let distribution_impl = if sample_weights.is_empty() || !has_fields {
+        TokenStream::new()
+    } else if total_weight == 0 {
+        let err = zero_weight_error(name).to_compile_error();
+        quote! { #strum_module_path::_private_if_rand_feature! { #err } }
+    } else {
+        let message = format!("{} has no values to sample", name);
+        quote! {
+            #strum_module_path::_private_if_rand_feature! {
+                #[automatically_derived]
+                impl #impl_generics #rand::distr::Distribution<#name #ty_generics> for #rand::distr::StandardUniform #where_clause {
+                    fn sample<R: #rand::Rng + ?::core::marker::Sized>(&self, rng: &mut R) -> #name #ty_generics {
+                        let iter = <#name #ty_generics as #strum_module_path::IntoEnumIterator>::iter();
+                        let counts: &[usize] = &[#(#sample_counts),*];
+                        let weights: &[u32] = &[#(#sample_weights),*];
+                        let total = counts
+                            .iter()
+                            .zip(weights.iter())
+                            .filter(|(&count, _)| count != 0)
+                            .map(|(_, &weight)| weight)
+                            .sum::<u32>();
+                        if total == 0 {
+                            ::core::panic!(#message);
+                        }
+
+                        let mut pick = #rand::Rng::random_range(rng, 0..total);
+                        let mut start = 0usize;
+                        for (&count, &weight) in counts.iter().zip(weights.iter()) {
+                            if count == 0 {
+                                continue;
+                            }
+                            if pick < weight {
+                                let idx = start + #rand::Rng::random_range(rng, 0..count);
+                                match iter.get(idx) {
+                                    ::core::option::Option::Some(value) => return value,
+                                    ::core::option::Option::None => ::core::unreachable!(),
+                                }
+                            }
+                            pick -= weight;
+                            start += count;
+                        }
+                        ::core::unreachable!()
+                    }
+                }
+            }
+        }
+    };
 
     // Create a string literal "MyEnumIter" to use in the debug impl.
     let iter_name_debug_struct =
@@ -74,7 +290,7 @@ pub fn enum_iter_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         #[allow(
             missing_copy_implementations,
         )]
-        #vis struct #iter_name #impl_generics {
+        #vis struct #iter_name #struct_generics {
             idx: usize,
             back_idx: usize,
             marker: ::core::marker::PhantomData #phantom_data,
@@ -94,12 +310,60 @@ pub fn enum_iter_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         #[automatically_derived]
         impl #impl_generics #iter_name #ty_generics #where_clause {
             fn get(&self, idx: usize) -> ::core::option::Option<#name #ty_generics> {
-                match idx {
-                    #(#arms),*
+                #get_body
+            }
+
+            #[inline]
+            fn variant_count() -> usize {
+                #variant_count
+            }
+
+            // Returns the range of indices at which `value`'s variant is yielded.
+            fn position(value: &#name #ty_generics) -> (usize, usize) {
+                #position_body
+            }
+
+            fn with_range(start: usize, end: usize) -> Self {
+                let end = if end < start { start } else { end };
+                #iter_name {
+                    idx: start,
+                    back_idx: Self::variant_count() - end,
+                    marker: ::core::marker::PhantomData,
                 }
             }
         }
 
+        #[automatically_derived]
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns an iterator over the variants of this enum, starting at `start`.
+            #[must_use]
+            pub fn iter_from(start: #name #ty_generics) -> #iter_name #ty_generics {
+                <#iter_name #ty_generics>::with_range(<#iter_name #ty_generics>::position(&start).0, <#iter_name #ty_generics>::variant_count())
+            }
+
+            /// Returns an iterator over the variants of this enum that are within `range`,
+            /// in declaration order.
+            #[must_use]
+            pub fn iter_range<R>(range: R) -> #iter_name #ty_generics
+            where
+                R: ::core::ops::RangeBounds<#name #ty_generics>,
+            {
+                let start = match range.start_bound() {
+                    ::core::ops::Bound::Included(start) => <#iter_name #ty_generics>::position(start).0,
+                    ::core::ops::Bound::Excluded(start) => <#iter_name #ty_generics>::position(start).1,
+                    ::core::ops::Bound::Unbounded => 0,
+                };
+                let end = match range.end_bound() {
+                    ::core::ops::Bound::Included(end) => <#iter_name #ty_generics>::position(end).1,
+                    ::core::ops::Bound::Excluded(end) => <#iter_name #ty_generics>::position(end).0,
+                    ::core::ops::Bound::Unbounded => <#iter_name #ty_generics>::variant_count(),
+                };
+                <#iter_name #ty_generics>::with_range(start, end)
+            }
+
+            #iter_with_names_fn
+        }
+
         #[automatically_derived]
         impl #impl_generics #strum_module_path::IntoEnumIterator for #name #ty_generics #where_clause {
             type Iterator = #iter_name #ty_generics;
@@ -125,18 +389,18 @@ pub fn enum_iter_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
 
             #[inline]
             fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
-                let t = if self.idx + self.back_idx >= #variant_count { 0 } else { #variant_count - self.idx - self.back_idx };
+                let t = if self.idx + self.back_idx >= Self::variant_count() { 0 } else { Self::variant_count() - self.idx - self.back_idx };
                 (t, ::core::option::Option::Some(t))
             }
 
             #[inline]
             fn nth(&mut self, n: usize) -> ::core::option::Option<<Self as Iterator>::Item> {
                 let idx = self.idx + n + 1;
-                if idx + self.back_idx > #variant_count {
-                    // We went past the end of the iterator. Freeze idx at #variant_count
+                if idx + self.back_idx > Self::variant_count() {
+                    // We went past the end of the iterator. Freeze idx at the variant count
                     // so that it doesn't overflow if the user calls this repeatedly.
                     // See PR #76 for context.
-                    self.idx = #variant_count;
+                    self.idx = Self::variant_count();
                     ::core::option::Option::None
                 } else {
                     self.idx = idx;
@@ -159,15 +423,15 @@ pub fn enum_iter_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             fn next_back(&mut self) -> ::core::option::Option<<Self as Iterator>::Item> {
                 let back_idx = self.back_idx + 1;
 
-                if self.idx + back_idx > #variant_count {
-                    // We went past the end of the iterator. Freeze back_idx at #variant_count
+                if self.idx + back_idx > Self::variant_count() {
+                    // We went past the end of the iterator. Freeze back_idx at the variant count
                     // so that it doesn't overflow if the user calls this repeatedly.
                     // See PR #76 for context.
-                    self.back_idx = #variant_count;
+                    self.back_idx = Self::variant_count();
                     ::core::option::Option::None
                 } else {
                     self.back_idx = back_idx;
-                    #iter_name::get(self, #variant_count - self.back_idx)
+                    #iter_name::get(self, Self::variant_count() - self.back_idx)
                 }
             }
         }
@@ -175,6 +439,10 @@ pub fn enum_iter_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         #[automatically_derived]
         impl #impl_generics ::core::iter::FusedIterator for #iter_name #ty_generics #where_clause { }
 
+        #par_iter_impl
+
+        #distribution_impl
+
         #[automatically_derived]
         impl #impl_generics ::core::clone::Clone for #iter_name #ty_generics #where_clause {
             #[inline]
@@ -188,3 +456,125 @@ pub fn enum_iter_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         }
     })
 }
+
+fn par_iter_impl(
+    ast: &DeriveInput,
+    generics: &Generics,
+    iter_name: &Ident,
+    strum_module_path: &Path,
+) -> TokenStream {
+    let name = &ast.ident;
+    let (_, ty_generics, _) = ast.generics.split_for_impl();
+    let mut generics = generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote! { #name #ty_generics: ::core::marker::Send });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let rayon = quote! { #strum_module_path::rayon::_private_rayon_reexport_for_macro };
+
+    // The implementation is only kept if the `rayon` feature of the main crate is enabled.
+    quote! {
+        #strum_module_path::_private_if_rayon_feature! {
+            #[automatically_derived]
+            impl #impl_generics #rayon::iter::IntoParallelIterator for #iter_name #ty_generics #where_clause {
+                type Iter = #strum_module_path::rayon::ParIter<Self>;
+                type Item = #name #ty_generics;
+
+                #[inline]
+                fn into_par_iter(self) -> Self::Iter {
+                    #strum_module_path::rayon::ParIter::new(self)
+                }
+            }
+        }
+    }
+}
+
+/// Generates the code yielding every combination of field values of a variant marked with
+/// `#[strum(iter_fields)]`. Returns the expression computing the number of combinations and
+/// a block that returns the combination at `idx` if there is one, or subtracts the number of
+/// combinations from `idx` otherwise. The last field varies the fastest.
+fn iter_fields_block(
+    name: &Ident,
+    variant: &Variant,
+    strum_module_path: &Path,
+) -> syn::Result<(TokenStream, TokenStream)> {
+    let ident = &variant.ident;
+    let mut field_counts = Vec::new();
+    let mut field_values = Vec::new();
+    for field in &variant.fields {
+        let ty = &field.ty;
+        let (count, value) = match field.get_variant_inner_properties()?.iter_values {
+            Some(values) => {
+                let count = values.len();
+                let indices = 0..count;
+                (
+                    quote! { #count },
+                    quote! {
+                        match i {
+                            #(#indices => #values,)*
+                            _ => return ::core::option::Option::None,
+                        }
+                    },
+                )
+            }
+            None if is_bool(ty) => (quote! { 2usize }, quote! { i == 1 }),
+            None => (
+                quote! { <#ty as #strum_module_path::IntoEnumIterator>::iter().len() },
+                quote! { <#ty as #strum_module_path::IntoEnumIterator>::iter().nth(i)? },
+            ),
+        };
+        field_counts.push(count);
+        field_values.push(value);
+    }
+
+    let bindings: Vec<_> = (0..field_values.len())
+        .map(|i| Ident::new(&format!("value_{}", i), Span::call_site()))
+        .collect();
+    let params = match &variant.fields {
+        Fields::Unit => quote! {},
+        Fields::Unnamed(_) => quote! { (#(#bindings),*) },
+        Fields::Named(fields) => {
+            let fields = fields
+                .named
+                .iter()
+                .map(|field| field.ident.as_ref().unwrap());
+            quote! { {#(#fields: #bindings),*} }
+        }
+    };
+
+    let count = quote! { (1usize #(* #field_counts)*) };
+    let assignments = bindings
+        .iter()
+        .zip(&field_counts)
+        .zip(&field_values)
+        .rev()
+        .map(|((binding, count), value)| {
+            quote! {
+                let #binding = {
+                    let count = #count;
+                    let i = rest % count;
+                    rest /= count;
+                    #value
+                };
+            }
+        });
+    let block = quote! {
+        {
+            let count = #count;
+            if idx < count {
+                let mut rest = idx;
+                #(#assignments)*
+                let _ = rest;
+                return ::core::option::Option::Some(#name::#ident #params);
+            }
+            idx -= count;
+        }
+    };
+
+    Ok((count, block))
+}
+
+fn is_bool(ty: &Type) -> bool {
+    matches!(ty, Type::Path(path) if path.qself.is_none() && path.path.is_ident("bool"))
+}
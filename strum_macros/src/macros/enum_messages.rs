@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Fields, LitStr};
 
-use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties};
+use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties, SkipTarget};
 
 pub fn enum_message_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
@@ -35,8 +35,7 @@ pub fn enum_message_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
 
         // You can't disable getting the serializations.
         {
-            let serialization_variants =
-                variant_properties.get_serializations(type_properties.case_style);
+            let serialization_variants = variant_properties.get_serializations(&type_properties);
 
             let count = serialization_variants.len();
             serializations.push(quote! {
@@ -48,7 +47,7 @@ pub fn enum_message_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         }
 
         // But you can disable the messages.
-        if variant_properties.disabled.is_some() {
+        if variant_properties.is_skipped(SkipTarget::Messages) {
             continue;
         }
 
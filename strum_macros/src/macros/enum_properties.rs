@@ -4,7 +4,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Fields, Lit};
 
-use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties};
+use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties, SkipTarget};
 
 #[derive(Hash, PartialEq, Eq)]
 enum PropertyType {
@@ -36,7 +36,7 @@ pub fn enum_properties_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         let variant_properties = variant.get_variant_properties()?;
         let mut arms: HashMap<_, _> = PROPERTY_TYPES.iter().map(|p| (p, Vec::new())).collect();
         // But you can disable the messages.
-        if variant_properties.disabled.is_some() {
+        if variant_properties.is_skipped(SkipTarget::Props) {
             continue;
         }
 
@@ -0,0 +1,513 @@
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields};
+
+use crate::helpers::{dense_variants, non_enum_error, HasTypeProperties};
+
+pub fn enum_set_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let vis = &ast.vis;
+    let type_properties = ast.get_type_properties()?;
+    let strum_module_path = type_properties.crate_module_path();
+
+    let variants = match &ast.data {
+        Data::Enum(v) => &v.variants,
+        _ => return Err(non_enum_error()),
+    };
+
+    if !ast.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &ast.generics,
+            "EnumSet doesn't support enums with generic parameters",
+        ));
+    }
+
+    let set_name = format_ident!("{}Set", name);
+    let iter_name = format_ident!("{}SetIter", name);
+
+    // match arms in the form `MyEnum::Variant => 1 << i,`
+    let mut bit_arms = Vec::new();
+    // match arms panicking for variants that can't be inserted
+    let mut insert_panics = Vec::new();
+    // match arms in the form `i => MyEnum::Variant,`
+    let mut from_index_arms = Vec::new();
+    // the names of each variant, as used by `Display`
+    let mut names = Vec::new();
+    // match arms in the form `"name" => 1 << i,`, as used by `EnumString`
+    let mut parse_arms = Vec::new();
+    for dense in dense_variants(variants)? {
+        let (variant, variant_properties) = (dense.variant, dense.properties);
+        let ident = &variant.ident;
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "EnumSet only supports enums with unit variants",
+            ));
+        }
+
+        // Sets use the same dense indices as `EnumIndex`.
+        let idx = match dense.index {
+            Some(idx) => Literal::usize_unsuffixed(idx),
+            None => {
+                let message = format!("{}::{} can't be stored in {}", name, ident, set_name);
+                bit_arms.push(quote! { #name::#ident => 0, });
+                insert_panics.push(quote! { #name::#ident => ::core::panic!(#message), });
+                continue;
+            }
+        };
+        bit_arms.push(quote! { #name::#ident => 1 << #idx, });
+        from_index_arms.push(quote! { #idx => #name::#ident, });
+        // Variants skipped for `parse` or `display` are still written and parsed, so that every
+        // set can be parsed back from the string it's written as.
+        names.push(variant_properties.get_preferred_name(&type_properties)?);
+        let is_ascii_case_insensitive = variant_properties
+            .ascii_case_insensitive
+            .unwrap_or(type_properties.ascii_case_insensitive);
+        for serialization in variant_properties.get_serializations(&type_properties) {
+            if is_ascii_case_insensitive {
+                parse_arms
+                    .push(quote! { s if s.eq_ignore_ascii_case(#serialization) => 1 << #idx, });
+            } else if serialization.as_lit().is_some() {
+                parse_arms.push(quote! { #serialization => 1 << #idx, });
+            } else {
+                // Non-literal values can't always be used as patterns.
+                parse_arms.push(quote! { s if s == #serialization => 1 << #idx, });
+            }
+        }
+    }
+
+    let count = from_index_arms.len();
+    let repr = match count {
+        0 => {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "EnumSet requires at least one non-disabled variant",
+            ))
+        }
+        1..=8 => format_ident!("u8"),
+        9..=16 => format_ident!("u16"),
+        17..=32 => format_ident!("u32"),
+        33..=64 => format_ident!("u64"),
+        65..=128 => format_ident!("u128"),
+        _ => {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "EnumSet supports at most 128 variants",
+            ))
+        }
+    };
+    let all_bits = Literal::u128_unsuffixed(if count == 128 {
+        u128::MAX
+    } else {
+        (1 << count) - 1
+    });
+    let set_name_str = set_name.to_string();
+    let insert_check = if insert_panics.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            #[allow(unreachable_patterns)]
+            match value {
+                #(#insert_panics)*
+                _ => {}
+            }
+        }
+    };
+
+    let doc_set = format!(
+        "A set of `{}` variants, stored as the bits of a `{}`.",
+        name, repr
+    );
+    let doc_iter = format!(
+        "An iterator over the variants in a `{}`, in declaration order.",
+        set_name
+    );
+
+    Ok(quote! {
+        #[doc = #doc_set]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #vis struct #set_name {
+            bits: #repr,
+        }
+
+        #[doc = #doc_iter]
+        #[derive(Clone)]
+        #vis struct #iter_name {
+            bits: #repr,
+        }
+
+        #[automatically_derived]
+        impl #set_name {
+            /// The set without any variant.
+            #vis const EMPTY: #set_name = #set_name { bits: 0 };
+
+            /// The set with every variant.
+            #vis const ALL: #set_name = #set_name { bits: #all_bits };
+
+            /// Returns the bit standing for `value`, or `0` if it can't be stored in the set.
+            #[inline]
+            const fn bit(value: #name) -> #repr {
+                match value {
+                    #(#bit_arms)*
+                }
+            }
+
+            /// Creates an empty set.
+            #[inline]
+            #vis const fn new() -> #set_name {
+                #set_name::EMPTY
+            }
+
+            /// Creates a set with every variant.
+            #[inline]
+            #vis const fn all() -> #set_name {
+                #set_name::ALL
+            }
+
+            /// Creates a set from its bits, where the bit `i` stands for the variant with index `i`.
+            /// Returns `None` if a bit doesn't stand for a variant.
+            #[inline]
+            #vis const fn from_bits(bits: #repr) -> ::core::option::Option<#set_name> {
+                if bits & !#set_name::ALL.bits == 0 {
+                    ::core::option::Option::Some(#set_name { bits })
+                } else {
+                    ::core::option::Option::None
+                }
+            }
+
+            /// Creates a set from its bits, ignoring the bits that don't stand for a variant.
+            #[inline]
+            #vis const fn from_bits_truncate(bits: #repr) -> #set_name {
+                #set_name { bits: bits & #set_name::ALL.bits }
+            }
+
+            /// Returns the bits of the set, where the bit `i` stands for the variant with index `i`.
+            #[inline]
+            #vis const fn bits(&self) -> #repr {
+                self.bits
+            }
+
+            /// Returns a copy of the set with `value` added.
+            #[must_use]
+            #[inline]
+            #vis const fn insert(self, value: #name) -> #set_name {
+                #insert_check
+                #set_name { bits: self.bits | #set_name::bit(value) }
+            }
+
+            /// Returns a copy of the set with `value` removed.
+            #[must_use]
+            #[inline]
+            #vis const fn remove(self, value: #name) -> #set_name {
+                #set_name { bits: self.bits & !#set_name::bit(value) }
+            }
+
+            /// Returns whether the set contains `value`.
+            #[inline]
+            #vis const fn contains(&self, value: #name) -> bool {
+                self.bits & #set_name::bit(value) != 0
+            }
+
+            /// Returns the variants that are in either set.
+            #[must_use]
+            #[inline]
+            #vis const fn union(self, other: #set_name) -> #set_name {
+                #set_name { bits: self.bits | other.bits }
+            }
+
+            /// Returns the variants that are in both sets.
+            #[must_use]
+            #[inline]
+            #vis const fn intersection(self, other: #set_name) -> #set_name {
+                #set_name { bits: self.bits & other.bits }
+            }
+
+            /// Returns the variants that are in this set but not in `other`.
+            #[must_use]
+            #[inline]
+            #vis const fn difference(self, other: #set_name) -> #set_name {
+                #set_name { bits: self.bits & !other.bits }
+            }
+
+            /// Returns the variants that are in exactly one of the sets.
+            #[must_use]
+            #[inline]
+            #vis const fn symmetric_difference(self, other: #set_name) -> #set_name {
+                #set_name { bits: self.bits ^ other.bits }
+            }
+
+            /// Returns the variants that aren't in this set.
+            #[must_use]
+            #[inline]
+            #vis const fn complement(self) -> #set_name {
+                #set_name { bits: !self.bits & #set_name::ALL.bits }
+            }
+
+            /// Returns whether every variant of this set is in `other`.
+            #[inline]
+            #vis const fn is_subset(&self, other: &#set_name) -> bool {
+                self.bits & !other.bits == 0
+            }
+
+            /// Returns whether every variant of `other` is in this set.
+            #[inline]
+            #vis const fn is_superset(&self, other: &#set_name) -> bool {
+                other.is_subset(self)
+            }
+
+            /// Returns whether the sets have no variant in common.
+            #[inline]
+            #vis const fn is_disjoint(&self, other: &#set_name) -> bool {
+                self.bits & other.bits == 0
+            }
+
+            /// Returns whether the set is empty.
+            #[inline]
+            #vis const fn is_empty(&self) -> bool {
+                self.bits == 0
+            }
+
+            /// Returns the number of variants in the set.
+            #[inline]
+            #vis const fn len(&self) -> usize {
+                self.bits.count_ones() as usize
+            }
+
+            /// Returns an iterator over the variants in the set, in declaration order.
+            #[inline]
+            #vis fn iter(&self) -> #iter_name {
+                #iter_name { bits: self.bits }
+            }
+        }
+
+        #[automatically_derived]
+        impl #iter_name {
+            #[inline]
+            fn variant(index: u32) -> #name {
+                match index {
+                    #(#from_index_arms)*
+                    _ => ::core::unreachable!(),
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::iter::Iterator for #iter_name {
+            type Item = #name;
+
+            #[inline]
+            fn next(&mut self) -> ::core::option::Option<#name> {
+                if self.bits == 0 {
+                    return ::core::option::Option::None;
+                }
+                let index = self.bits.trailing_zeros();
+                self.bits &= self.bits - 1;
+                ::core::option::Option::Some(#iter_name::variant(index))
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, ::core::option::Option<usize>) {
+                let len = self.bits.count_ones() as usize;
+                (len, ::core::option::Option::Some(len))
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::iter::DoubleEndedIterator for #iter_name {
+            #[inline]
+            fn next_back(&mut self) -> ::core::option::Option<#name> {
+                if self.bits == 0 {
+                    return ::core::option::Option::None;
+                }
+                let index = #repr::BITS - 1 - self.bits.leading_zeros();
+                self.bits &= !(1 << index);
+                ::core::option::Option::Some(#iter_name::variant(index))
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::iter::ExactSizeIterator for #iter_name {}
+
+        #[automatically_derived]
+        impl ::core::iter::FusedIterator for #iter_name {}
+
+        #[automatically_derived]
+        impl ::core::iter::IntoIterator for #set_name {
+            type Item = #name;
+            type IntoIter = #iter_name;
+
+            #[inline]
+            fn into_iter(self) -> #iter_name {
+                self.iter()
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::iter::IntoIterator for &#set_name {
+            type Item = #name;
+            type IntoIter = #iter_name;
+
+            #[inline]
+            fn into_iter(self) -> #iter_name {
+                self.iter()
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::iter::FromIterator<#name> for #set_name {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #name>>(iter: I) -> #set_name {
+                let mut set = #set_name::EMPTY;
+                ::core::iter::Extend::extend(&mut set, iter);
+                set
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::iter::Extend<#name> for #set_name {
+            fn extend<I: ::core::iter::IntoIterator<Item = #name>>(&mut self, iter: I) {
+                for value in iter {
+                    *self = self.insert(value);
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::convert::From<#name> for #set_name {
+            #[inline]
+            fn from(value: #name) -> #set_name {
+                #set_name::EMPTY.insert(value)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::BitOr for #set_name {
+            type Output = #set_name;
+
+            #[inline]
+            fn bitor(self, rhs: #set_name) -> #set_name {
+                self.union(rhs)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::BitAnd for #set_name {
+            type Output = #set_name;
+
+            #[inline]
+            fn bitand(self, rhs: #set_name) -> #set_name {
+                self.intersection(rhs)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::BitXor for #set_name {
+            type Output = #set_name;
+
+            #[inline]
+            fn bitxor(self, rhs: #set_name) -> #set_name {
+                self.symmetric_difference(rhs)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::Sub for #set_name {
+            type Output = #set_name;
+
+            #[inline]
+            fn sub(self, rhs: #set_name) -> #set_name {
+                self.difference(rhs)
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::Not for #set_name {
+            type Output = #set_name;
+
+            #[inline]
+            fn not(self) -> #set_name {
+                self.complement()
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::BitOrAssign for #set_name {
+            #[inline]
+            fn bitor_assign(&mut self, rhs: #set_name) {
+                *self = self.union(rhs);
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::BitAndAssign for #set_name {
+            #[inline]
+            fn bitand_assign(&mut self, rhs: #set_name) {
+                *self = self.intersection(rhs);
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::BitXorAssign for #set_name {
+            #[inline]
+            fn bitxor_assign(&mut self, rhs: #set_name) {
+                *self = self.symmetric_difference(rhs);
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::ops::SubAssign for #set_name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: #set_name) {
+                *self = self.difference(rhs);
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Display for #set_name {
+            /// Writes the names of the variants in the set, as used by `Display`, separated by `|`.
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                const NAMES: &[&str] = &[#(#names),*];
+                let mut bits = self.bits;
+                let mut first = true;
+                while bits != 0 {
+                    if !first {
+                        f.write_str("|")?;
+                    }
+                    first = false;
+                    f.write_str(NAMES[bits.trailing_zeros() as usize])?;
+                    bits &= bits - 1;
+                }
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::fmt::Debug for #set_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_tuple(#set_name_str)
+                    .field(&::core::format_args!("{}", self))
+                    .finish()
+            }
+        }
+
+        #[automatically_derived]
+        impl ::core::str::FromStr for #set_name {
+            type Err = #strum_module_path::ParseError;
+
+            /// Parses the names of variants, as used by `EnumString`, separated by `|`. Whitespace
+            /// around the names is ignored and an empty string is an empty set.
+            fn from_str(s: &str) -> ::core::result::Result<#set_name, #strum_module_path::ParseError> {
+                let mut bits: #repr = 0;
+                if s.trim().is_empty() {
+                    return ::core::result::Result::Ok(#set_name { bits });
+                }
+                for s in s.split('|') {
+                    bits |= match s.trim() {
+                        #(#parse_arms)*
+                        _ => return ::core::result::Result::Err(#strum_module_path::ParseError::VariantNotFound),
+                    };
+                }
+                ::core::result::Result::Ok(#set_name { bits })
+            }
+        }
+    })
+}
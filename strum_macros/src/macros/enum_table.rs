@@ -1,22 +1,22 @@
-use proc_macro2::{Span, TokenStream};
-use quote::{format_ident, quote};
-use syn::{spanned::Spanned, Data, DeriveInput, Fields};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_quote, spanned::Spanned, Data, DeriveInput, Fields, GenericParam, Ident, WherePredicate,
+};
 
-use crate::helpers::{non_enum_error, snakify, HasStrumVariantProperties};
+use crate::helpers::{
+    fresh_param, non_enum_error, snakify, HasStrumVariantProperties, HasTypeProperties, SkipTarget,
+};
 
 pub fn enum_table_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
     let gen = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = gen.split_for_impl();
     let vis = &ast.vis;
+    let type_properties = ast.get_type_properties()?;
+    let strum_module_path = type_properties.crate_module_path();
     let mut doc_comment = format!("A map over the variants of `{}`", name);
 
-    if gen.lifetimes().count() > 0 {
-        return Err(syn::Error::new(
-            Span::call_site(),
-            "`EnumTable` doesn't support enums with lifetimes.",
-        ));
-    }
-
     let variants = match &ast.data {
         Data::Enum(v) => &v.variants,
         _ => return Err(non_enum_error()),
@@ -24,55 +24,123 @@ pub fn enum_table_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
 
     let table_name = format_ident!("{}Table", name);
 
+    // Values of data-carrying enums can't be built from their variants alone, so the table is keyed
+    // by a generated enum with a unit variant for each of them instead.
+    let mut has_data = false;
+    for variant in variants {
+        if !variant
+            .get_variant_properties()?
+            .is_skipped(SkipTarget::Table)
+        {
+            has_data |= !matches!(variant.fields, Fields::Unit);
+        }
+    }
+    let key_name = format_ident!("{}Key", table_name);
+    let (base_name, base_ty, base_impl) = if has_data {
+        (key_name.clone(), quote! { #key_name }, quote! { impl })
+    } else {
+        (
+            name.clone(),
+            quote! { #name #ty_generics },
+            quote! { impl #impl_generics },
+        )
+    };
+    let base_where = if has_data { None } else { where_clause };
+
+    // With `table_disabled = "compile_error"`, the table is keyed by a generated enum without
+    // the disabled variants, so that indexing it can't panic.
+    let restricted = type_properties.table_disabled_compile_error;
+    let key_ty = if restricted || has_data {
+        quote! { #key_name }
+    } else {
+        base_ty.clone()
+    };
+    let key_name = if restricted || has_data {
+        key_name
+    } else {
+        base_name.clone()
+    };
+
     // the identifiers of each variant, in PascalCase
     let mut pascal_idents = Vec::new();
     // the identifiers of each struct field, in snake_case
     let mut snake_idents = Vec::new();
-    // match arms in the form `MyEnumTable::Variant => &self.variant,`
+    // the names of each variant, as used by `Display`, which are only needed for serde
+    let mut names = Vec::new();
+    // match arms in the form `MyEnumTable::Variant => &self.values[i],`
     let mut get_matches = Vec::new();
-    // match arms in the form `MyEnumTable::Variant => &mut self.variant,`
+    // match arms in the form `MyEnumTable::Variant => &mut self.values[i],`
     let mut get_matches_mut = Vec::new();
-    // match arms in the form `MyEnumTable::Variant => self.variant = new_value`
+    // match arms in the form `MyEnumTable::Variant => table.values[i] = new_value`
     let mut set_matches = Vec::new();
-    // struct fields of the form `variant: func(MyEnum::Variant),*
+    // array elements of the form `func(MyEnum::Variant),`
     let mut closure_fields = Vec::new();
-    // struct fields of the form `variant: func(MyEnum::Variant, self.variant),`
+    // array elements of the form `func(MyEnum::Variant, &self.values[i]),`
     let mut transform_fields = Vec::new();
 
     // identifiers for disabled variants
     let mut disabled_variants = Vec::new();
+    // match arms in the form `MyEnum::Variant { .. } => &self.values[i],`
+    let mut value_get_matches = Vec::new();
+    // match arms in the form `MyEnum::Variant { .. } => &mut self.values[i],`
+    let mut value_get_matches_mut = Vec::new();
     // match arms for disabled variants
     let mut disabled_matches = Vec::new();
+    // match arms for disabled variants, when indexing with a value of the enum
+    let mut value_disabled_matches = Vec::new();
+    // match arms ignoring disabled variants
+    let mut disabled_ignores = Vec::new();
+    // match arms in the form `MyEnum::Variant { .. } => Some(i),`, or `None` for disabled variants
+    let mut position_matches = Vec::new();
+    // the variants of the generated key enum of data-carrying enums, in declaration order
+    let mut key_variants = Vec::new();
 
     for variant in variants {
+        let variant_properties = variant.get_variant_properties()?;
         // skip disabled variants
-        if variant.get_variant_properties()?.disabled.is_some() {
+        if variant_properties.is_skipped(SkipTarget::Table) {
             let disabled_ident = &variant.ident;
             let panic_message = format!(
                 "Can't use `{}` with `{}` - variant is disabled for gratte features",
                 disabled_ident, table_name
             );
             disabled_variants.push(disabled_ident);
-            disabled_matches.push(quote!(#name::#disabled_ident => panic!(#panic_message),));
+            // the key enum of restricted data-carrying tables has no disabled variants
+            if !(restricted && has_data) {
+                position_matches.push(
+                    quote!(#base_name::#disabled_ident { .. } => ::core::option::Option::None,),
+                );
+            }
+            if !restricted {
+                key_variants.push(disabled_ident);
+                disabled_ignores.push(quote!(#key_name::#disabled_ident { .. } => {}));
+                disabled_matches.push(
+                    quote!(#key_name::#disabled_ident { .. } => ::core::panic!(#panic_message),),
+                );
+                value_disabled_matches
+                    .push(quote!(#name::#disabled_ident { .. } => ::core::panic!(#panic_message),));
+            }
             continue;
         }
 
-        // Error on variants with data
-        if !matches!(variant.fields, Fields::Unit) {
-            return Err(syn::Error::new(
-                variant.fields.span(),
-                "`EnumTable` doesn't support enums with non-unit variants",
-            ));
-        };
-
         let pascal_case = &variant.ident;
         let snake_case = format_ident!("_{}", snakify(&pascal_case.to_string()));
+        // the dense index of the variant in the table, skipping disabled variants
+        let idx = snake_idents.len();
 
-        get_matches.push(quote! {#name::#pascal_case => &self.#snake_case,});
-        get_matches_mut.push(quote! {#name::#pascal_case => &mut self.#snake_case,});
-        set_matches.push(quote! {#name::#pascal_case => self.#snake_case = new_value,});
-        closure_fields.push(quote! {#snake_case: func(#name::#pascal_case),});
-        transform_fields.push(quote! {#snake_case: func(#name::#pascal_case, &self.#snake_case),});
+        get_matches.push(quote! {#key_name::#pascal_case => &self.values[#idx],});
+        get_matches_mut.push(quote! {#key_name::#pascal_case => &mut self.values[#idx],});
+        value_get_matches.push(quote! {#name::#pascal_case { .. } => &self.values[#idx],});
+        value_get_matches_mut.push(quote! {#name::#pascal_case { .. } => &mut self.values[#idx],});
+        set_matches.push(quote! {#key_name::#pascal_case => table.values[#idx] = new_value,});
+        closure_fields.push(quote! {func(#key_name::#pascal_case),});
+        transform_fields.push(quote! {func(#key_name::#pascal_case, &self.values[#idx]),});
+        position_matches
+            .push(quote! {#base_name::#pascal_case { .. } => ::core::option::Option::Some(#idx),});
+        if type_properties.table_serde {
+            names.push(variant_properties.get_preferred_name(&type_properties)?);
+        }
+        key_variants.push(pascal_case);
         pascal_idents.push(pascal_case);
         snake_idents.push(snake_case);
     }
@@ -86,7 +154,7 @@ pub fn enum_table_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     }
 
     // if the index operation can panic, add that to the documentation
-    if !disabled_variants.is_empty() {
+    if !disabled_variants.is_empty() && !restricted {
         doc_comment.push_str(&format!(
             "\n# Panics\nIndexing `{}` with any of the following variants will cause a panic:",
             table_name
@@ -105,69 +173,721 @@ pub fn enum_table_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         table_name, name
     );
     let doc_transform = format!("Create a new `{}` by running a function on each variant of `{}` and the corresponding value in the current `{0}`", table_name, name);
+    let doc_from_array = format!(
+        "Create a new `{}` from the values of the variants of `{}`, in declaration order.",
+        table_name, name
+    );
+    let doc_as_slice = format!(
+        "Returns the values of the table as a slice, in the declaration order of the variants of `{}`.",
+        name
+    );
+    let doc_as_mut_slice = format!(
+        "Returns the values of the table as a mutable slice, in the declaration order of the variants of `{}`.",
+        name
+    );
+    let doc_get =
+        "Returns a reference to the value of `key`, or `None` if its variant is disabled.";
+    let doc_get_mut =
+        "Returns a mutable reference to the value of `key`, or `None` if its variant is disabled.";
+    let doc_replace = "Replaces the value of `key`, returning the previous value, or gives `value` back if the variant of `key` is disabled.";
+    let doc_try_set =
+        "Sets the value of `key`, or gives `value` back if the variant of `key` is disabled.";
+
+    // Tables of data-carrying enums can also be accessed with a reference to a value, as with `Index`.
+    let (get_key_ty, get_key) = if has_data && !restricted {
+        (
+            quote! { impl ::core::convert::Into<#base_ty> },
+            quote! { ::core::convert::Into::into(key) },
+        )
+    } else {
+        (base_ty.clone(), quote! { key })
+    };
     let doc_filled = format!(
         "Create a new `{}` with the same value in each field.",
         table_name
     );
+    let doc_iter = format!(
+        "Returns an iterator over the variants of `{}` and references to their values, in declaration order.",
+        name
+    );
+    let doc_iter_mut = format!(
+        "Returns an iterator over the variants of `{}` and mutable references to their values, in declaration order.",
+        name
+    );
+    let doc_keys = format!(
+        "Returns an iterator over the variants of `{}` stored in the table, in declaration order.",
+        name
+    );
+    let doc_values =
+        "Returns an iterator over references to the values of the table, in declaration order.";
+    let doc_values_mut = "Returns an iterator over mutable references to the values of the table, in declaration order.";
+    let doc_map = format!("Create a new `{}` by running a function on each variant of `{}` and the corresponding value, consuming the current `{0}`", table_name, name);
+    let doc_zip = format!(
+        "Create a new `{}` pairing the values of this `{0}` with the values of `other`.",
+        table_name
+    );
+    let doc_zip_with = format!("Create a new `{}` by running a function on each variant of `{}` and the corresponding values in this `{0}` and in `other`.", table_name, name);
+    let doc_fold = "Folds every variant and its value into an accumulator, in declaration order.";
+    let doc_any = "Returns whether `func` returns `true` for any variant and its value, in declaration order. `func` isn't called after it returns `true`.";
+    let doc_every = "Returns whether `func` returns `true` for every variant and its value, in declaration order. `func` isn't called after it returns `false`. This is the equivalent of `Iterator::all`, as `all` collapses tables of `Option`s.";
+    let doc_max_by_key = "Returns the variant whose value gives the maximum value of `func`. If several variants are maximum, the last one is returned.";
     let doc_option_all = format!("Converts `{}<Option<T>>` into `Option<{0}<T>>`. Returns `Some` if all fields are `Some`, otherwise returns `None`.", table_name);
     let doc_result_all_ok = format!("Converts `{}<Result<T, E>>` into `Result<{0}<T>, E>`. Returns `Ok` if all fields are `Ok`, otherwise returns `Err`.", table_name);
 
+    // The table takes the generic parameters of the enum, followed by the type of its values.
+    // The names of the generic parameters used in the impls must not clash with the enum's.
+    let t = fresh_param(gen, "T");
+    let u = fresh_param(gen, "U");
+    let e = fresh_param(gen, "E");
+    let f = fresh_param(gen, "F");
+    let v = fresh_param(gen, "V");
+    let b = fresh_param(gen, "B");
+    let enum_args: Vec<_> = gen
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Const(param) => param.ident.to_token_stream(),
+        })
+        .collect();
+    let table_ty = |value: TokenStream| quote! { #table_name<#(#enum_args,)* #value> };
+    let table_t = table_ty(quote! { #t });
+    let table_u = table_ty(quote! { #u });
+    let table_option = table_ty(quote! { ::core::option::Option<#t> });
+    let table_result = table_ty(quote! { ::core::result::Result<#t, #e> });
+    let with_params = |params: &[&Ident], bounds: &[TokenStream]| {
+        let mut generics = gen.clone();
+        generics
+            .params
+            .extend(params.iter().map(|param| -> GenericParam {
+                parse_quote! { #param }
+            }));
+        let predicates = &mut generics.make_where_clause().predicates;
+        predicates.extend(bounds.iter().map(|bound| -> WherePredicate {
+            parse_quote! { #bound }
+        }));
+        generics
+    };
+    let table_generics = with_params(&[&t], &[]);
+    let (table_impl_generics, table_params, table_where) = table_generics.split_for_impl();
+    let mut ref_generics = table_generics.clone();
+    ref_generics.params.insert(0, parse_quote! { '__table });
+    let (ref_impl_generics, _, _) = ref_generics.split_for_impl();
+    let generics_for = |bound: TokenStream| with_params(&[&t], &[quote! { #t: #bound }]);
+    let clone_generics = generics_for(quote! { ::core::clone::Clone });
+    let (clone_impl_generics, _, clone_where) = clone_generics.split_for_impl();
+    let debug_generics = generics_for(quote! { ::core::fmt::Debug });
+    let (debug_impl_generics, _, debug_where) = debug_generics.split_for_impl();
+    let default_generics = generics_for(quote! { ::core::default::Default });
+    let (default_impl_generics, _, default_where) = default_generics.split_for_impl();
+    let eq_generics = generics_for(quote! { ::core::cmp::PartialEq });
+    let (eq_impl_generics, _, eq_where) = eq_generics.split_for_impl();
+    let full_eq_generics = generics_for(quote! { ::core::cmp::Eq });
+    let (full_eq_impl_generics, _, full_eq_where) = full_eq_generics.split_for_impl();
+    let hash_generics = generics_for(quote! { ::core::hash::Hash });
+    let (hash_impl_generics, _, hash_where) = hash_generics.split_for_impl();
+    let result_generics = with_params(&[&t, &e], &[]);
+    let (result_impl_generics, _, result_where) = result_generics.split_for_impl();
+
+    // The table doesn't store values of the enum, but still needs to use all of its parameters.
+    let lifetimes = gen.lifetimes().map(|param| &param.lifetime);
+    let types = gen.type_params().map(|param| &param.ident);
+    let phantom_data =
+        quote! { ::core::marker::PhantomData<fn() -> ( #(&#lifetimes (),)* #(#types,)* )> };
+    let count = snake_idents.len();
+    let table_name_str = table_name.to_string();
+    let keys: Vec<_> = pascal_idents
+        .iter()
+        .map(|ident| quote! { #key_name::#ident })
+        .collect();
+    let other_idents: Vec<_> = snake_idents
+        .iter()
+        .map(|ident| format_ident!("{}_other", ident))
+        .collect();
+    let table_pair = table_ty(quote! { (#t, #u) });
+    let table_v = table_ty(quote! { #v });
+    let op_impl = |trait_name: Ident, method: Ident| {
+        let generics = generics_for(quote! { ::core::ops::#trait_name<Output = #t> });
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::ops::#trait_name for #table_t #where_clause {
+                type Output = #table_t;
+
+                #[inline]
+                fn #method(self, rhs: #table_t) -> #table_t {
+                    let [#(#snake_idents),*] = self.values;
+                    let [#(#other_idents),*] = rhs.values;
+                    #table_name::from_array([#(::core::ops::#trait_name::#method(#snake_idents, #other_idents)),*])
+                }
+            }
+        }
+    };
+    let op_assign_impl = |trait_name: Ident, method: Ident| {
+        let generics = generics_for(quote! { ::core::ops::#trait_name });
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::ops::#trait_name for #table_t #where_clause {
+                #[inline]
+                fn #method(&mut self, rhs: #table_t) {
+                    for (value, other) in self.values.iter_mut().zip(rhs.values) {
+                        ::core::ops::#trait_name::#method(value, other);
+                    }
+                }
+            }
+        }
+    };
+    let op_impls = [
+        op_impl(format_ident!("Add"), format_ident!("add")),
+        op_impl(format_ident!("Sub"), format_ident!("sub")),
+        op_assign_impl(format_ident!("AddAssign"), format_ident!("add_assign")),
+        op_assign_impl(format_ident!("SubAssign"), format_ident!("sub_assign")),
+    ];
+
+    // Values of data-carrying enums can't be used as keys, but they can still index the table.
+    let value_index_impls = if has_data && !restricted {
+        quote! {
+            #[automatically_derived]
+            impl #ref_impl_generics ::core::ops::Index<&'__table #name #ty_generics> for #table_t #table_where {
+                type Output = #t;
+
+                #[inline]
+                fn index(&self, idx: &'__table #name #ty_generics) -> &#t {
+                    match idx {
+                        #(#value_get_matches)*
+                        #(#value_disabled_matches)*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            impl #ref_impl_generics ::core::ops::IndexMut<&'__table #name #ty_generics> for #table_t #table_where {
+                #[inline]
+                fn index_mut(&mut self, idx: &'__table #name #ty_generics) -> &mut #t {
+                    match idx {
+                        #(#value_get_matches_mut)*
+                        #(#value_disabled_matches)*
+                    }
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+    let snake_strs = snake_idents.iter().map(|ident| ident.to_string());
+
+    // Tables are serialized as maps from the names of the variants to their values. The
+    // implementations are only generated on request, so that enabling the `serde` feature of the
+    // main crate doesn't require the names of every table to be computable.
+    let serde_impls = if !type_properties.table_serde {
+        TokenStream::new()
+    } else {
+        let serde =
+            quote! { #strum_module_path::_private_serde_reexport_for_macro_if_serde_feature };
+        let serialize_generics = generics_for(quote! { #serde::Serialize });
+        let (serialize_impl_generics, _, serialize_where) = serialize_generics.split_for_impl();
+        let deserialize_bound = if type_properties.table_serde_default {
+            quote! { #serde::Deserialize<'__de> + ::core::default::Default }
+        } else {
+            quote! { #serde::Deserialize<'__de> }
+        };
+        let mut deserialize_generics = generics_for(deserialize_bound);
+        deserialize_generics
+            .params
+            .insert(0, parse_quote! { '__de });
+        let (deserialize_impl_generics, _, deserialize_where) =
+            deserialize_generics.split_for_impl();
+        let indices = 0..count;
+        let missing_values = indices.clone().map(|idx| {
+            if type_properties.table_serde_default {
+                quote! { ::core::default::Default::default() }
+            } else {
+                quote! { return ::core::result::Result::Err(#serde::de::Error::missing_field(NAMES[#idx])) }
+            }
+        });
+        let expecting = format!("a map from the variants of `{}` to values", name);
+
+        quote! {
+            #[automatically_derived]
+            impl #serialize_impl_generics #serde::Serialize for #table_t #serialize_where {
+                fn serialize<__S: #serde::Serializer>(&self, serializer: __S) -> ::core::result::Result<__S::Ok, __S::Error> {
+                    const NAMES: &[&str] = &[#(#names),*];
+                    let mut map = #serde::Serializer::serialize_map(serializer, ::core::option::Option::Some(#count))?;
+                    #(#serde::ser::SerializeMap::serialize_entry(&mut map, NAMES[#indices], &self.values[#indices])?;)*
+                    #serde::ser::SerializeMap::end(map)
+                }
+            }
+
+            #[automatically_derived]
+            impl #deserialize_impl_generics #serde::Deserialize<'__de> for #table_t #deserialize_where {
+                fn deserialize<__D: #serde::Deserializer<'__de>>(deserializer: __D) -> ::core::result::Result<Self, __D::Error> {
+                    const NAMES: &[&str] = &[#(#names),*];
+
+                    // The index of a value in the table, deserialized from the name of its variant.
+                    struct __Key(usize);
+
+                    impl<'de> #serde::Deserialize<'de> for __Key {
+                        fn deserialize<D: #serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                            struct KeyVisitor;
+
+                            impl<'de> #serde::de::Visitor<'de> for KeyVisitor {
+                                type Value = __Key;
+
+                                fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                                    f.write_str("a variant name")
+                                }
+
+                                fn visit_str<E: #serde::de::Error>(self, value: &str) -> ::core::result::Result<__Key, E> {
+                                    match NAMES.iter().position(|name| *name == value) {
+                                        ::core::option::Option::Some(idx) => ::core::result::Result::Ok(__Key(idx)),
+                                        ::core::option::Option::None => ::core::result::Result::Err(E::unknown_field(value, NAMES)),
+                                    }
+                                }
+                            }
+
+                            #serde::Deserializer::deserialize_str(deserializer, KeyVisitor)
+                        }
+                    }
+
+                    struct __Visitor #table_generics (::core::marker::PhantomData<fn() -> #table_t>) #table_where;
+
+                    impl #deserialize_impl_generics #serde::de::Visitor<'__de> for __Visitor #table_params #deserialize_where {
+                        type Value = #table_t;
+
+                        fn expecting(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                            f.write_str(#expecting)
+                        }
+
+                        fn visit_map<__A: #serde::de::MapAccess<'__de>>(self, mut map: __A) -> ::core::result::Result<Self::Value, __A::Error> {
+                            let mut values: [::core::option::Option<#t>; #count] = ::core::array::from_fn(|_| ::core::option::Option::None);
+                            while let ::core::option::Option::Some(__Key(idx)) = #serde::de::MapAccess::next_key(&mut map)? {
+                                if values[idx].is_some() {
+                                    return ::core::result::Result::Err(#serde::de::Error::duplicate_field(NAMES[idx]));
+                                }
+                                values[idx] = ::core::option::Option::Some(#serde::de::MapAccess::next_value(&mut map)?);
+                            }
+
+                            let [#(#snake_idents),*] = values;
+                            ::core::result::Result::Ok(#table_name::from_array([#(
+                                match #snake_idents {
+                                    ::core::option::Option::Some(value) => value,
+                                    ::core::option::Option::None => #missing_values,
+                                }
+                            ),*]))
+                        }
+                    }
+
+                    #serde::Deserializer::deserialize_map(deserializer, __Visitor(::core::marker::PhantomData))
+                }
+            }
+        }
+    };
+
+    let key_enum = if has_data {
+        let doc_key = if restricted {
+            format!(
+                "The variants of `{}` that aren't disabled, which are the keys of `{}`.",
+                name, table_name
+            )
+        } else {
+            format!(
+                "The variants of `{}`, which are the keys of `{}`.",
+                name, table_name
+            )
+        };
+        let mut value_generics = gen.clone();
+        value_generics.params.insert(0, parse_quote! { '__value });
+        let (value_impl_generics, _, _) = value_generics.split_for_impl();
+        let value_ty = quote! { &'__value #name #ty_generics };
+        let conversion = if restricted {
+            quote! {
+                #[automatically_derived]
+                impl #value_impl_generics ::core::convert::TryFrom<#value_ty> for #key_name #where_clause {
+                    type Error = #value_ty;
+
+                    #[inline]
+                    fn try_from(value: #value_ty) -> ::core::result::Result<Self, #value_ty> {
+                        #[allow(unreachable_patterns)]
+                        match value {
+                            #(#name::#pascal_idents { .. } => ::core::result::Result::Ok(#key_name::#pascal_idents),)*
+                            _ => ::core::result::Result::Err(value),
+                        }
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #[automatically_derived]
+                impl #value_impl_generics ::core::convert::From<#value_ty> for #key_name #where_clause {
+                    #[inline]
+                    fn from(value: #value_ty) -> Self {
+                        match value {
+                            #(#name::#key_variants { .. } => #key_name::#key_variants,)*
+                        }
+                    }
+                }
+            }
+        };
+        quote! {
+            #[doc = #doc_key]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #vis enum #key_name {
+                #(#key_variants,)*
+            }
+
+            #conversion
+        }
+    } else if restricted {
+        let doc_key = format!(
+            "The variants of `{}` that aren't disabled, which are the keys of `{}`.",
+            base_name, table_name
+        );
+        quote! {
+            #[doc = #doc_key]
+            #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+            #vis enum #key_name {
+                #(#pascal_idents,)*
+            }
+
+            #[automatically_derived]
+            #base_impl ::core::convert::From<#key_name> for #base_ty #base_where {
+                #[inline]
+                fn from(key: #key_name) -> Self {
+                    match key {
+                        #(#key_name::#pascal_idents => #base_name::#pascal_idents,)*
+                    }
+                }
+            }
+
+            #[automatically_derived]
+            #base_impl ::core::convert::TryFrom<#base_ty> for #key_name #base_where {
+                type Error = #base_ty;
+
+                #[inline]
+                fn try_from(value: #base_ty) -> ::core::result::Result<Self, #base_ty> {
+                    #[allow(unreachable_patterns)]
+                    match value {
+                        #(#base_name::#pascal_idents => ::core::result::Result::Ok(#key_name::#pascal_idents),)*
+                        _ => ::core::result::Result::Err(value),
+                    }
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
     Ok(quote! {
+        #key_enum
+
         #[doc = #doc_comment]
         #[allow(
             missing_copy_implementations,
         )]
-        #[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
-        #vis struct #table_name<T> {
-            #(#snake_idents: T,)*
+        #vis struct #table_name #table_generics #table_where {
+            values: [#t; #count],
+            __marker: #phantom_data,
+        }
+
+        #[automatically_derived]
+        impl #debug_impl_generics ::core::fmt::Debug for #table_t #debug_where {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                let [#(#snake_idents),*] = &self.values;
+                f.debug_struct(#table_name_str)
+                    #(.field(#snake_strs, #snake_idents))*
+                    .finish()
+            }
+        }
+
+        #[automatically_derived]
+        impl #clone_impl_generics ::core::clone::Clone for #table_t #clone_where {
+            #[inline]
+            fn clone(&self) -> Self {
+                #table_name {
+                    values: ::core::clone::Clone::clone(&self.values),
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #default_impl_generics ::core::default::Default for #table_t #default_where {
+            #[inline]
+            fn default() -> Self {
+                #table_name {
+                    values: ::core::array::from_fn(|_| ::core::default::Default::default()),
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #eq_impl_generics ::core::cmp::PartialEq for #table_t #eq_where {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                self.values == other.values
+            }
         }
 
         #[automatically_derived]
-        impl<T: Clone> #table_name<T> {
+        impl #full_eq_impl_generics ::core::cmp::Eq for #table_t #full_eq_where {}
+
+        #[automatically_derived]
+        impl #hash_impl_generics ::core::hash::Hash for #table_t #hash_where {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(&self.values, state);
+            }
+        }
+
+        #[automatically_derived]
+        impl #clone_impl_generics #table_t #clone_where {
             #[doc = #doc_filled]
-            #vis fn filled(value: T) -> #table_name<T> {
+            #vis fn filled(value: #t) -> #table_t {
                 #table_name {
-                    #(#snake_idents: value.clone(),)*
+                    values: ::core::array::from_fn(|_| value.clone()),
+                    __marker: ::core::marker::PhantomData,
                 }
             }
         }
 
         #[automatically_derived]
-        impl<T> #table_name<T> {
+        impl #table_impl_generics #table_t #table_where {
             #[doc = #doc_new]
             #[inline]
-            #vis fn new(
-                #(#snake_idents: T,)*
-            ) -> #table_name<T> {
+            #vis const fn new(
+                #(#snake_idents: #t,)*
+            ) -> #table_t {
+                #table_name {
+                    values: [#(#snake_idents),*],
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+
+            #[doc = #doc_from_array]
+            #[inline]
+            #vis const fn from_array(values: [#t; #count]) -> #table_t {
                 #table_name {
-                    #(#snake_idents,)*
+                    values,
+                    __marker: ::core::marker::PhantomData,
                 }
             }
 
+            #[doc = #doc_as_slice]
+            #[inline]
+            #vis fn as_slice(&self) -> &[#t] {
+                &self.values
+            }
+
+            #[doc = #doc_as_mut_slice]
+            #[inline]
+            #vis fn as_mut_slice(&mut self) -> &mut [#t] {
+                &mut self.values
+            }
+
             #[doc = #doc_closure]
             #[inline]
-            #vis fn from_closure<F: FnMut(#name)->T>(mut func: F) -> #table_name<T> {
+            #vis fn from_closure<#f: FnMut(#key_ty) -> #t>(mut func: #f) -> #table_t {
               #table_name {
-                #(#closure_fields)*
+                values: [#(#closure_fields)*],
+                __marker: ::core::marker::PhantomData,
               }
             }
 
             #[doc = #doc_transform]
             #[inline]
-            #vis fn transform<U, F: FnMut(#name, &T)->U>(&self, mut func: F) -> #table_name<U> {
+            #vis fn transform<#u, #f: FnMut(#key_ty, &#t) -> #u>(&self, mut func: #f) -> #table_u {
               #table_name {
-                #(#transform_fields)*
+                values: [#(#transform_fields)*],
+                __marker: ::core::marker::PhantomData,
               }
             }
 
+            #[doc = #doc_get]
+            #[inline]
+            #vis fn get(&self, key: #get_key_ty) -> ::core::option::Option<&#t> {
+                Self::position(&#get_key).map(|idx| &self.values[idx])
+            }
+
+            #[doc = #doc_get_mut]
+            #[inline]
+            #vis fn get_mut(&mut self, key: #get_key_ty) -> ::core::option::Option<&mut #t> {
+                Self::position(&#get_key).map(move |idx| &mut self.values[idx])
+            }
+
+            #[doc = #doc_replace]
+            #[inline]
+            #vis fn replace(&mut self, key: #get_key_ty, value: #t) -> ::core::result::Result<#t, #t> {
+                match self.get_mut(key) {
+                    ::core::option::Option::Some(slot) => ::core::result::Result::Ok(::core::mem::replace(slot, value)),
+                    ::core::option::Option::None => ::core::result::Result::Err(value),
+                }
+            }
+
+            #[doc = #doc_try_set]
+            #[inline]
+            #vis fn try_set(&mut self, key: #get_key_ty, value: #t) -> ::core::result::Result<(), #t> {
+                self.replace(key, value).map(|_| ())
+            }
+
+            // Returns the index of the value of `key`, if its variant isn't disabled.
+            #[inline]
+            fn position(key: &#base_ty) -> ::core::option::Option<usize> {
+                match key {
+                    #(#position_matches)*
+                }
+            }
+
+            #[doc = #doc_iter]
+            #[inline]
+            #vis fn iter(&self) -> ::core::array::IntoIter<(#key_ty, &#t), #count> {
+                let [#(#snake_idents),*] = &self.values;
+                [#((#key_name::#pascal_idents, #snake_idents)),*].into_iter()
+            }
+
+            #[doc = #doc_iter_mut]
+            #[inline]
+            #vis fn iter_mut(&mut self) -> ::core::array::IntoIter<(#key_ty, &mut #t), #count> {
+                let [#(#snake_idents),*] = &mut self.values;
+                [#((#key_name::#pascal_idents, #snake_idents)),*].into_iter()
+            }
+
+            #[doc = #doc_keys]
+            #[inline]
+            #vis fn keys(&self) -> ::core::array::IntoIter<#key_ty, #count> {
+                [#(#key_name::#pascal_idents),*].into_iter()
+            }
+
+            #[doc = #doc_values]
+            #[inline]
+            #vis fn values(&self) -> ::core::array::IntoIter<&#t, #count> {
+                let [#(#snake_idents),*] = &self.values;
+                [#(#snake_idents),*].into_iter()
+            }
+
+            #[doc = #doc_values_mut]
+            #[inline]
+            #vis fn values_mut(&mut self) -> ::core::array::IntoIter<&mut #t, #count> {
+                let [#(#snake_idents),*] = &mut self.values;
+                [#(#snake_idents),*].into_iter()
+            }
+
+            #[doc = #doc_map]
+            #[inline]
+            #vis fn map<#u, #f: FnMut(#key_ty, #t) -> #u>(self, mut func: #f) -> #table_u {
+                let [#(#snake_idents),*] = self.values;
+                #table_name::from_array([#(func(#keys, #snake_idents)),*])
+            }
+
+            #[doc = #doc_zip]
+            #[inline]
+            #vis fn zip<#u>(self, other: #table_u) -> #table_pair {
+                let [#(#snake_idents),*] = self.values;
+                let [#(#other_idents),*] = other.values;
+                #table_name::from_array([#((#snake_idents, #other_idents)),*])
+            }
+
+            #[doc = #doc_zip_with]
+            #[inline]
+            #vis fn zip_with<#u, #v, #f: FnMut(#key_ty, #t, #u) -> #v>(self, other: #table_u, mut func: #f) -> #table_v {
+                let [#(#snake_idents),*] = self.values;
+                let [#(#other_idents),*] = other.values;
+                #table_name::from_array([#(func(#keys, #snake_idents, #other_idents)),*])
+            }
+
+            #[doc = #doc_fold]
+            #[inline]
+            #vis fn fold<#b, #f: FnMut(#b, #key_ty, #t) -> #b>(self, init: #b, mut func: #f) -> #b {
+                let [#(#snake_idents),*] = self.values;
+                let acc = init;
+                #(let acc = func(acc, #keys, #snake_idents);)*
+                acc
+            }
+
+            #[doc = #doc_any]
+            #[inline]
+            #vis fn any<#f: FnMut(#key_ty, &#t) -> bool>(&self, mut func: #f) -> bool {
+                let [#(#snake_idents),*] = &self.values;
+                false #(|| func(#keys, #snake_idents))*
+            }
+
+            #[doc = #doc_every]
+            #[inline]
+            #vis fn every<#f: FnMut(#key_ty, &#t) -> bool>(&self, mut func: #f) -> bool {
+                let [#(#snake_idents),*] = &self.values;
+                true #(&& func(#keys, #snake_idents))*
+            }
+
+            #[doc = #doc_max_by_key]
+            #[inline]
+            #vis fn max_by_key<#b: ::core::cmp::Ord, #f: FnMut(&#t) -> #b>(&self, mut func: #f) -> #key_ty {
+                match self.iter().max_by_key(|(_, value)| func(value)) {
+                    ::core::option::Option::Some((key, _)) => key,
+                    ::core::option::Option::None => ::core::unreachable!(),
+                }
+            }
         }
 
+        #(#op_impls)*
+
         #[automatically_derived]
-        impl<T> ::core::ops::Index<#name> for #table_name<T> {
-            type Output = T;
+        impl #table_impl_generics ::core::iter::IntoIterator for #table_t #table_where {
+            type Item = (#key_ty, #t);
+            type IntoIter = ::core::array::IntoIter<(#key_ty, #t), #count>;
 
             #[inline]
-            fn index(&self, idx: #name) -> &T {
+            fn into_iter(self) -> Self::IntoIter {
+                let [#(#snake_idents),*] = self.values;
+                [#((#key_name::#pascal_idents, #snake_idents)),*].into_iter()
+            }
+        }
+
+        #[automatically_derived]
+        impl #ref_impl_generics ::core::iter::IntoIterator for &'__table #table_t #table_where {
+            type Item = (#key_ty, &'__table #t);
+            type IntoIter = ::core::array::IntoIter<(#key_ty, &'__table #t), #count>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        #[automatically_derived]
+        impl #ref_impl_generics ::core::iter::IntoIterator for &'__table mut #table_t #table_where {
+            type Item = (#key_ty, &'__table mut #t);
+            type IntoIter = ::core::array::IntoIter<(#key_ty, &'__table mut #t), #count>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter_mut()
+            }
+        }
+
+        #[automatically_derived]
+        impl #default_impl_generics ::core::iter::FromIterator<(#key_ty, #t)> for #table_t #default_where {
+            /// Creates a table from pairs of variants and values. Variants missing from the
+            /// iterator are given the default value, variants appearing several times keep their
+            /// last value, and disabled variants are ignored.
+            fn from_iter<I: ::core::iter::IntoIterator<Item = (#key_ty, #t)>>(iter: I) -> Self {
+                let mut table: Self = ::core::default::Default::default();
+                for (key, new_value) in iter {
+                    match key {
+                        #(#set_matches)*
+                        #(#disabled_ignores)*
+                    }
+                }
+                table
+            }
+        }
+
+        #[automatically_derived]
+        impl #table_impl_generics ::core::ops::Index<#key_ty> for #table_t #table_where {
+            type Output = #t;
+
+            #[inline]
+            fn index(&self, idx: #key_ty) -> &#t {
                 match idx {
                     #(#get_matches)*
                     #(#disabled_matches)*
@@ -176,9 +896,9 @@ pub fn enum_table_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         }
 
         #[automatically_derived]
-        impl<T> ::core::ops::IndexMut<#name> for #table_name<T> {
+        impl #table_impl_generics ::core::ops::IndexMut<#key_ty> for #table_t #table_where {
             #[inline]
-            fn index_mut(&mut self, idx: #name) -> &mut T {
+            fn index_mut(&mut self, idx: #key_ty) -> &mut #t {
                 match idx {
                     #(#get_matches_mut)*
                     #(#disabled_matches)*
@@ -186,16 +906,21 @@ pub fn enum_table_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             }
         }
 
+        #value_index_impls
+
+        #strum_module_path::_private_if_serde_feature! {
+            #serde_impls
+        }
+
         #[automatically_derived]
-        impl<T> #table_name<::core::option::Option<T>> {
+        impl #table_impl_generics #table_option #table_where {
             #[doc = #doc_option_all]
             #[inline]
-            #vis fn all(self) -> ::core::option::Option<#table_name<T>> {
-                if let #table_name {
-                    #(#snake_idents: ::core::option::Option::Some(#snake_idents),)*
-                } = self {
+            #vis fn all(self) -> ::core::option::Option<#table_t> {
+                if let [#(::core::option::Option::Some(#snake_idents)),*] = self.values {
                     ::core::option::Option::Some(#table_name {
-                        #(#snake_idents,)*
+                        values: [#(#snake_idents),*],
+                        __marker: ::core::marker::PhantomData,
                     })
                 } else {
                     ::core::option::Option::None
@@ -204,12 +929,14 @@ pub fn enum_table_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         }
 
         #[automatically_derived]
-        impl<T, E> #table_name<::core::result::Result<T, E>> {
+        impl #result_impl_generics #table_result #result_where {
             #[doc = #doc_result_all_ok]
             #[inline]
-            #vis fn all_ok(self) -> ::core::result::Result<#table_name<T>, E> {
+            #vis fn all_ok(self) -> ::core::result::Result<#table_t, #e> {
+                let [#(#snake_idents),*] = self.values;
                 ::core::result::Result::Ok(#table_name {
-                    #(#snake_idents: self.#snake_idents?,)*
+                    values: [#(#snake_idents?),*],
+                    __marker: ::core::marker::PhantomData,
                 })
             }
         }
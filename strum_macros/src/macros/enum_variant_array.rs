@@ -2,7 +2,10 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Fields};
 
-use crate::helpers::{non_enum_error, non_unit_variant_error, HasTypeProperties};
+use crate::helpers::{
+    add_weight, dense_variants, non_enum_error, non_unit_variant_error, zero_weight_error,
+    HasTypeProperties,
+};
 
 pub fn static_variants_array_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
@@ -17,19 +20,98 @@ pub fn static_variants_array_inner(ast: &DeriveInput) -> syn::Result<TokenStream
     let type_properties = ast.get_type_properties()?;
     let strum_module_path = type_properties.crate_module_path();
 
-    let idents = variants
-        .iter()
-        .cloned()
-        .map(|v| match v.fields {
-            Fields::Unit => Ok(v.ident),
-            _ => Err(non_unit_variant_error()),
-        })
-        .collect::<syn::Result<Vec<_>>>()?;
+    let mut idents = Vec::new();
+    let mut weights = Vec::new();
+    let mut total_weight = 0u32;
+    for dense in dense_variants(variants)? {
+        let (variant, variant_properties) = (dense.variant, dense.properties);
+        if dense.index.is_none() {
+            continue;
+        }
+
+        match variant.fields {
+            Fields::Unit => idents.push(&variant.ident),
+            _ => return Err(non_unit_variant_error()),
+        }
+        weights.push(variant_properties.weight());
+        total_weight = add_weight(total_weight, variant_properties.weight(), variant)?;
+    }
+
+    // `random` and the `Distribution` implementation are only kept if the `rand` feature of the
+    // main crate is enabled, and so is the check that some variant can be picked. The default
+    // implementation of `random` picks variants uniformly.
+    let rand = quote! { #strum_module_path::_private_rand_reexport_for_macro_if_rand_feature };
+    let random_fn = if total_weight != 0 && weights.iter().any(|&weight| weight != 1) {
+        let picks = weights
+            .iter()
+            .enumerate()
+            .filter(|(_, &weight)| weight != 0)
+            .map(|(idx, weight)| {
+                quote! {
+                    if pick < #weight {
+                        return ::core::option::Option::Some(&Self::VARIANTS[#idx]);
+                    }
+                    pick -= #weight;
+                }
+            });
+        quote! {
+            #strum_module_path::_private_if_rand_feature! {
+                fn random<R: #rand::Rng + ?::core::marker::Sized>(
+                    rng: &mut R,
+                ) -> ::core::option::Option<&'static Self> {
+                    let mut pick = #rand::Rng::random_range(rng, 0..#total_weight);
+                    #(#picks)*
+                    let _ = pick;
+                    ::core::unreachable!()
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    // Variants are picked by value, so that they don't need to implement `Clone`.
+    let distribution_impl = if weights.is_empty() {
+        TokenStream::new()
+    } else if total_weight == 0 {
+        let err = zero_weight_error(name).to_compile_error();
+        quote! { #strum_module_path::_private_if_rand_feature! { #err } }
+    } else {
+        let picks = idents
+            .iter()
+            .zip(&weights)
+            .filter(|(_, &weight)| weight != 0)
+            .map(|(ident, weight)| {
+                quote! {
+                    if pick < #weight {
+                        return #name::#ident;
+                    }
+                    pick -= #weight;
+                }
+            });
+        quote! {
+            #strum_module_path::_private_if_rand_feature! {
+                #[automatically_derived]
+                impl #impl_generics #rand::distr::Distribution<#name #ty_generics> for #rand::distr::StandardUniform #where_clause {
+                    fn sample<R: #rand::Rng + ?::core::marker::Sized>(&self, rng: &mut R) -> #name #ty_generics {
+                        let mut pick = #rand::Rng::random_range(rng, 0..#total_weight);
+                        #(#picks)*
+                        let _ = pick;
+                        ::core::unreachable!()
+                    }
+                }
+            }
+        }
+    };
 
     Ok(quote! {
         #[automatically_derived]
         impl #impl_generics #strum_module_path::VariantArray for #name #ty_generics #where_clause {
             const VARIANTS: &'static [Self] = &[ #(#name::#idents),* ];
+
+            #random_fn
         }
+
+        #distribution_impl
     })
 }
@@ -1,8 +1,8 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, LitStr};
+use syn::{Data, DeriveInput};
 
-use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties};
+use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties, SkipTarget};
 
 pub fn enum_variant_names_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
@@ -18,17 +18,15 @@ pub fn enum_variant_names_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let type_properties = ast.get_type_properties()?;
     let strum_module_path = type_properties.crate_module_path();
 
-    let names = variants
-        .iter()
-        .map(|v| {
-            let props = v.get_variant_properties()?;
-            Ok(props.get_preferred_name(
-                type_properties.case_style,
-                type_properties.prefix.as_ref(),
-                type_properties.suffix.as_ref(),
-            ))
-        })
-        .collect::<syn::Result<Vec<LitStr>>>()?;
+    let mut names = Vec::new();
+    for variant in variants {
+        let props = variant.get_variant_properties()?;
+        if props.is_skipped(SkipTarget::Names) {
+            continue;
+        }
+
+        names.push(props.get_preferred_name(&type_properties)?);
+    }
 
     Ok(quote! {
         #[automatically_derived]
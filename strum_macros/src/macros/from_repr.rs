@@ -1,20 +1,24 @@
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{Data, DeriveInput, Fields, Type};
 
-use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties};
+use crate::helpers::{
+    generic_bounds, non_enum_error, non_single_field_variant_error, occurrence_error,
+    HasStrumVariantProperties, HasTypeProperties, SkipTarget,
+};
 
 pub fn from_repr_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
     let gen = &ast.generics;
     let (impl_generics, ty_generics, where_clause) = gen.split_for_impl();
     let vis = &ast.vis;
+    let type_properties = ast.get_type_properties()?;
+    let strum_module_path = type_properties.crate_module_path();
 
     let mut discriminant_type: Type = syn::parse("usize".parse().unwrap()).unwrap();
-    if let Some(type_path) = ast
-        .get_type_properties()
-        .ok()
-        .and_then(|tp| tp.enum_repr)
+    if let Some(type_path) = type_properties
+        .enum_repr
+        .clone()
         .and_then(|repr_ts| syn::parse2::<Type>(repr_ts).ok())
     {
         if let Type::Path(path) = type_path.clone() {
@@ -31,29 +35,78 @@ pub fn from_repr_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         }
     }
 
-    if gen.lifetimes().count() > 0 {
-        return Err(syn::Error::new(
-            Span::call_site(),
-            "This macro doesn't support enums with lifetimes. \
-             The resulting enums would be unbounded.",
-        ));
-    }
-
     let variants = match &ast.data {
         Data::Enum(v) => &v.variants,
         _ => return Err(non_enum_error()),
     };
 
     let mut arms = Vec::new();
+    let mut to_repr_arms = Vec::new();
     let mut constant_defs = Vec::new();
     let mut has_additional_data = false;
+    let mut all_unit = true;
     let mut prev_const_var_ident = None;
+    let mut field_types = Vec::new();
+    let mut catch_all_kw = None;
+    let mut catch_all_arm = None;
     for variant in variants {
-        if variant.get_variant_properties()?.disabled.is_some() {
+        let ident = &variant.ident;
+        let variant_properties = variant.get_variant_properties()?;
+
+        // Skipped variants still have a discriminant, which the following variants build upon.
+        let const_var_str = format!("{}_DISCRIMINANT", variant.ident);
+        let const_var_ident = format_ident!("{}", const_var_str);
+
+        let const_val_expr = match &variant.discriminant {
+            Some((_, expr)) => quote! { #expr },
+            None => match &prev_const_var_ident {
+                Some(prev) => quote! { #prev + 1 },
+                None => quote! { 0 },
+            },
+        };
+
+        constant_defs.push(quote! {
+            #[allow(non_upper_case_globals)]
+            const #const_var_ident: #discriminant_type = #const_val_expr;
+        });
+        prev_const_var_ident = Some(const_var_ident.clone());
+
+        // The catch-all variant holds the discriminants that don't match any other variant.
+        if let Some(kw) = variant_properties.catch_all {
+            if !variant_properties.is_skipped(SkipTarget::Repr) {
+                if let Some(fst_kw) = catch_all_kw {
+                    return Err(occurrence_error(fst_kw, kw, "catch_all"));
+                }
+                catch_all_kw = Some(kw);
+
+                let pattern = match &variant.fields {
+                    Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                        quote! { #name::#ident(value) }
+                    }
+                    Fields::Named(fields) if fields.named.len() == 1 => {
+                        let field = fields.named[0].ident.as_ref().unwrap();
+                        quote! { #name::#ident { #field: value } }
+                    }
+                    _ => return Err(non_single_field_variant_error("catch_all")),
+                };
+                catch_all_arm = Some(quote! {
+                    value => ::core::option::Option::Some(#pattern)
+                });
+                // The stored value is returned as is, even if it is the discriminant of another
+                // variant, in which case `from_repr` doesn't give this variant back.
+                to_repr_arms.push(quote! { #pattern => value });
+                continue;
+            }
+        }
+
+        all_unit &= matches!(variant.fields, Fields::Unit);
+        to_repr_arms.push(quote! { #name::#ident { .. } => #const_var_ident });
+
+        if variant_properties.is_skipped(SkipTarget::Repr) {
             continue;
         }
+        field_types.extend(variant.fields.iter().map(|field| &field.ty));
 
-        let ident = &variant.ident;
         let params = match &variant.fields {
             Fields::Unit => quote! {},
             Fields::Unnamed(fields) => {
@@ -71,28 +124,74 @@ pub fn from_repr_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
                 quote! { {#(#fields: ::core::default::Default::default()),*} }
             }
         };
+        arms.push(quote! {v if v == #const_var_ident => ::core::option::Option::Some(#name::#ident #params)});
+    }
 
-        let const_var_str = format!("{}_DISCRIMINANT", variant.ident);
-        let const_var_ident = format_ident!("{}", const_var_str);
+    let is_infallible = catch_all_arm.is_some();
+    arms.push(catch_all_arm.unwrap_or_else(|| quote! { _ => ::core::option::Option::None }));
 
-        let const_val_expr = match &variant.discriminant {
-            Some((_, expr)) => quote! { #expr },
-            None => match &prev_const_var_ident {
-                Some(prev) => quote! { #prev + 1 },
-                None => quote! { 0 },
-            },
-        };
+    // Only `from_repr` needs the fields to implement `Default`, so the bounds are put on it
+    // rather than on the impl.
+    let default_bounds = generic_bounds(field_types, gen, &quote! { ::core::default::Default });
+    let fn_where_clause = if default_bounds.is_empty() {
+        quote! {}
+    } else {
+        quote! { where #(#default_bounds),* }
+    };
+    let mut try_from_generics = gen.clone();
+    try_from_generics
+        .make_where_clause()
+        .predicates
+        .extend(default_bounds);
+    let try_from_where_clause = &try_from_generics.where_clause;
 
-        constant_defs.push(quote! {
-            #[allow(non_upper_case_globals)]
-            const #const_var_ident: #discriminant_type = #const_val_expr;
-        });
-        arms.push(quote! {v if v == #const_var_ident => ::core::option::Option::Some(#name::#ident #params)});
+    // With a catch-all variant, every discriminant can be converted into the enum, and `TryFrom`
+    // comes from the blanket implementation for `From`.
+    let try_from_impl = if is_infallible {
+        quote! {
+            #[automatically_derived]
+            #[allow(clippy::use_self)]
+            impl #impl_generics ::core::convert::From<#discriminant_type> for #name #ty_generics #try_from_where_clause {
+                #[inline]
+                fn from(discriminant: #discriminant_type) -> #name #ty_generics {
+                    match <#name #ty_generics>::from_repr(discriminant) {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => ::core::unreachable!(),
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[automatically_derived]
+            #[allow(clippy::use_self)]
+            impl #impl_generics ::core::convert::TryFrom<#discriminant_type> for #name #ty_generics #try_from_where_clause {
+                type Error = #strum_module_path::FromReprError<#discriminant_type>;
 
-        prev_const_var_ident = Some(const_var_ident);
-    }
+                #[inline]
+                fn try_from(discriminant: #discriminant_type) -> ::core::result::Result<#name #ty_generics, Self::Error> {
+                    <#name #ty_generics>::from_repr(discriminant).ok_or(#strum_module_path::FromReprError(discriminant))
+                }
+            }
+        }
+    };
 
-    arms.push(quote! { _ => ::core::option::Option::None });
+    // The enum can only be losslessly converted into its representation without `to_repr` if it
+    // has no data, besides the value of the catch-all variant.
+    let from_impl = if all_unit {
+        quote! {
+            #[automatically_derived]
+            #[allow(clippy::use_self)]
+            impl #impl_generics ::core::convert::From<#name #ty_generics> for #discriminant_type #where_clause {
+                #[inline]
+                fn from(value: #name #ty_generics) -> #discriminant_type {
+                    value.to_repr()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     let const_if_possible = if has_additional_data {
         quote! {}
@@ -106,12 +205,40 @@ pub fn from_repr_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         impl #impl_generics #name #ty_generics #where_clause {
             #[doc = "Try to create [Self] from the raw representation"]
             #[inline]
-            #vis #const_if_possible fn from_repr(discriminant: #discriminant_type) -> Option<#name #ty_generics> {
+            #vis #const_if_possible fn from_repr(discriminant: #discriminant_type) -> Option<#name #ty_generics> #fn_where_clause {
                 #(#constant_defs)*
                 match discriminant {
                     #(#arms),*
                 }
             }
+
+            #[doc = "Returns the raw representation of [Self]"]
+            #[inline]
+            #vis const fn to_repr(&self) -> #discriminant_type {
+                #(#constant_defs)*
+                match *self {
+                    #(#to_repr_arms),*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        #[allow(clippy::use_self)]
+        impl #impl_generics #strum_module_path::FromRepr for #name #ty_generics #try_from_where_clause {
+            type Repr = #discriminant_type;
+
+            #[inline]
+            fn from_repr(discriminant: #discriminant_type) -> ::core::option::Option<#name #ty_generics> {
+                <#name #ty_generics>::from_repr(discriminant)
+            }
+
+            #[inline]
+            fn to_repr(&self) -> #discriminant_type {
+                <#name #ty_generics>::to_repr(self)
+            }
         }
+
+        #try_from_impl
+        #from_impl
     })
 }
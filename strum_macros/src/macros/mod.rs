@@ -1,14 +1,20 @@
+pub mod enum_array;
 pub mod enum_count;
+pub mod enum_cycle;
 pub mod enum_discriminants;
+pub mod enum_index;
 pub mod enum_is;
 pub mod enum_iter;
 pub mod enum_messages;
 pub mod enum_properties;
+pub mod enum_set;
 pub mod enum_table;
 pub mod enum_try_as;
 pub mod enum_variant_array;
 pub mod enum_variant_names;
 pub mod from_repr;
+pub mod variant_iterator;
+pub mod variant_metadata;
 
 mod strings;
 
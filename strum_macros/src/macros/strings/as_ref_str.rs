@@ -4,6 +4,7 @@ use syn::{parse_quote, Data, DeriveInput, Fields};
 
 use crate::helpers::{
     non_enum_error, non_single_field_variant_error, HasStrumVariantProperties, HasTypeProperties,
+    SkipTarget,
 };
 
 fn get_arms<F>(ast: &DeriveInput, transparent_fn: F) -> syn::Result<Vec<TokenStream>>
@@ -23,7 +24,7 @@ where
         let ident = &variant.ident;
         let variant_properties = variant.get_variant_properties()?;
 
-        if variant_properties.disabled.is_some() {
+        if variant_properties.is_skipped(SkipTarget::Display) {
             continue;
         }
 
@@ -40,11 +41,7 @@ where
         // Look at all the serialize attributes.
         // Use `to_string` attribute (not `as_ref_str` or something) to keep things consistent
         // (i.e. always `enum.as_ref().to_string() == enum.to_string()`).
-        let output = variant_properties.get_preferred_name(
-            type_properties.case_style,
-            type_properties.prefix.as_ref(),
-            type_properties.suffix.as_ref(),
-        );
+        let output = variant_properties.get_preferred_name(&type_properties)?;
         let params = match variant.fields {
             Fields::Unit => quote! {},
             Fields::Unnamed(..) => quote! { (..) },
@@ -1,9 +1,10 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
-use syn::{punctuated::Punctuated, Data, DeriveInput, Fields, LitStr, Token};
+use syn::{punctuated::Punctuated, Data, DeriveInput, Fields, Token};
 
 use crate::helpers::{
     non_enum_error, non_single_field_variant_error, HasStrumVariantProperties, HasTypeProperties,
+    SkipTarget, StrValue,
 };
 
 pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
@@ -21,7 +22,7 @@ pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         let ident = &variant.ident;
         let variant_properties = variant.get_variant_properties()?;
 
-        if variant_properties.disabled.is_some() {
+        if variant_properties.is_skipped(SkipTarget::Display) {
             continue;
         }
 
@@ -36,11 +37,20 @@ pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         }
 
         // Look at all the serialize attributes.
-        let output = variant_properties.get_preferred_name(
-            type_properties.case_style,
-            type_properties.prefix.as_ref(),
-            type_properties.suffix.as_ref(),
-        );
+        let output = variant_properties.get_preferred_name(&type_properties)?;
+
+        // Interpolation requires a string literal, which can't be built from a non-literal
+        // `prefix` or `suffix`.
+        if output.as_lit().is_none() {
+            if let Some(to_string) = &variant_properties.to_string {
+                if !capture_format_strings(to_string)?.is_empty() {
+                    return Err(syn::Error::new_spanned(
+                        to_string,
+                        "Interpolation is not supported with a non-literal strum(prefix) or strum(suffix)",
+                    ));
+                }
+            }
+        }
 
         let params = match variant.fields {
             Fields::Unit => quote! {},
@@ -174,7 +184,7 @@ pub fn display_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     })
 }
 
-fn capture_format_string_idents(string_literal: &LitStr) -> syn::Result<Vec<Ident>> {
+fn capture_format_string_idents(string_literal: &StrValue) -> syn::Result<Vec<Ident>> {
     capture_format_strings(string_literal)?
         .into_iter()
         .map(|ident| {
@@ -188,7 +198,13 @@ fn capture_format_string_idents(string_literal: &LitStr) -> syn::Result<Vec<Iden
         .collect()
 }
 
-fn capture_format_strings(string_literal: &LitStr) -> syn::Result<Vec<String>> {
+fn capture_format_strings(value: &StrValue) -> syn::Result<Vec<String>> {
+    // Only string literals can be used as format strings.
+    let string_literal = match value.as_lit() {
+        Some(string_literal) => string_literal,
+        None => return Ok(Vec::new()),
+    };
+
     // Remove escaped brackets
     let format_str = string_literal.value().replace("{{", "").replace("}}", "");
 
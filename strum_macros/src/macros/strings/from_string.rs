@@ -1,10 +1,11 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Fields};
+use syn::{punctuated::Punctuated, Data, DeriveInput, Fields, Ident, Token, Variant};
 
 use crate::helpers::{
-    missing_parse_err_attr_error, non_enum_error, occurrence_error, HasInnerVariantProperties,
-    HasStrumVariantProperties, HasTypeProperties,
+    missing_parse_err_attr_error, non_enum_error, non_single_field_variant_error, occurrence_error,
+    type_props::StrumTypeProperties, HasInnerVariantProperties, HasStrumVariantProperties,
+    HasTypeProperties, SkipTarget,
 };
 
 pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
@@ -23,8 +24,14 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         return Err(missing_parse_err_attr_error());
     }
 
+    if type_properties.verify_roundtrip {
+        verify_roundtrip(variants, &type_properties)?;
+    }
+
     let mut default_kw = None;
     let mut default_match_arm = None;
+    let mut catch_all_kw = None;
+    let mut catch_all_variant = None;
 
     let mut phf_exact_match_arms = Vec::new();
     let mut standard_match_arms = Vec::new();
@@ -32,7 +39,7 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         let ident = &variant.ident;
         let variant_properties = variant.get_variant_properties()?;
 
-        if variant_properties.disabled.is_some() {
+        if variant_properties.is_skipped(SkipTarget::Parse) {
             continue;
         }
 
@@ -64,6 +71,29 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             continue;
         }
 
+        // Like `default`, but the unknown string is parsed into the field, so that the same
+        // variant can hold unknown discriminants with `FromRepr`.
+        if let Some(kw) = variant_properties.catch_all {
+            if let Some(fst_kw) = catch_all_kw {
+                return Err(occurrence_error(fst_kw, kw, "catch_all"));
+            }
+
+            catch_all_kw = Some(kw);
+
+            catch_all_variant = Some(match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    quote! { #name::#ident(value) }
+                }
+                Fields::Named(ref f) if f.named.len() == 1 => {
+                    let field_name = f.named.last().unwrap().ident.as_ref().unwrap();
+                    quote! { #name::#ident { #field_name: value } }
+                }
+                _ => return Err(non_single_field_variant_error("catch_all")),
+            });
+
+            continue;
+        }
+
         let params = match &variant.fields {
             Fields::Unit => quote! {},
             Fields::Unnamed(fields) => {
@@ -103,8 +133,9 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
             .unwrap_or(type_properties.ascii_case_insensitive);
 
         // If we don't have any custom variants, add the default serialized name.
-        for serialization in variant_properties.get_serializations(type_properties.case_style) {
+        for serialization in variant_properties.get_serializations(&type_properties) {
             if type_properties.use_phf {
+                let serialization = serialization.require_lit("strum(use_phf)")?;
                 phf_exact_match_arms.push(quote! { #serialization => #name::#ident #params, });
 
                 if is_ascii_case_insensitive {
@@ -120,13 +151,26 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
                     standard_match_arms.push(quote! { s if s.eq_ignore_ascii_case(#serialization) => #name::#ident #params, });
                 }
             } else if !is_ascii_case_insensitive {
-                standard_match_arms.push(quote! { #serialization => #name::#ident #params, });
+                if serialization.as_lit().is_some() {
+                    standard_match_arms.push(quote! { #serialization => #name::#ident #params, });
+                } else {
+                    // Non-literal values can't always be used as patterns.
+                    standard_match_arms
+                        .push(quote! { s if s == #serialization => #name::#ident #params, });
+                }
             } else {
                 standard_match_arms.push(quote! { s if s.eq_ignore_ascii_case(#serialization) => #name::#ident #params, });
             }
         }
     }
 
+    if let (Some(default_kw), Some(catch_all_kw)) = (default_kw, catch_all_kw) {
+        let mut e =
+            syn::Error::new_spanned(catch_all_kw, "`catch_all` can't be used with `default`");
+        e.combine(syn::Error::new_spanned(default_kw, "`default` used here"));
+        return Err(e);
+    }
+
     // Determine the error type on FromStr and TryFrom based on what the user
     // has configured and whether there is a default variant.
     let is_infallible = default_match_arm.is_some();
@@ -152,6 +196,16 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     } else {
         quote! { return ::core::result::Result::Err(#strum_module_path::ParseError::VariantNotFound) }
     };
+    let default_match_arm = if let Some(catch_all_variant) = catch_all_variant {
+        quote! {
+            match ::core::str::FromStr::from_str(s) {
+                ::core::result::Result::Ok(value) => #catch_all_variant,
+                ::core::result::Result::Err(_) => #default_match_arm,
+            }
+        }
+    } else {
+        default_match_arm
+    };
 
     let mut match_expression = if standard_match_arms.is_empty() {
         default_match_arm
@@ -225,3 +279,92 @@ pub fn from_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         #from_impl
     })
 }
+
+/// Makes sure that the preferred name of every unit variant (the one produced by `Display`,
+/// `AsRefStr`, etc.) is parsed back into the same variant by the generated parser.
+fn verify_roundtrip(
+    variants: &Punctuated<Variant, Token![,]>,
+    type_properties: &StrumTypeProperties,
+) -> syn::Result<()> {
+    const USAGE: &str = "strum(verify_roundtrip)";
+
+    // The strings the generated parser looks for, in the order it looks for them,
+    // along with whether the comparison is case-insensitive.
+    let mut serializations: Vec<(&Ident, String, bool)> = Vec::new();
+    for variant in variants {
+        let variant_properties = variant.get_variant_properties()?;
+        if variant_properties.is_skipped(SkipTarget::Parse)
+            || variant_properties.default.is_some()
+            || variant_properties.catch_all.is_some()
+        {
+            continue;
+        }
+
+        let is_ascii_case_insensitive = variant_properties
+            .ascii_case_insensitive
+            .unwrap_or(type_properties.ascii_case_insensitive);
+        for serialization in variant_properties.get_serializations(type_properties) {
+            let value = serialization.require_lit(USAGE)?.value();
+            serializations.push((&variant.ident, value, is_ascii_case_insensitive));
+        }
+    }
+
+    let parse = |s: &str| {
+        // With phf, exact matches (including the lowercase and UPPERCASE versions of
+        // case-insensitive serializations) are looked up before anything else.
+        let phf_match = type_properties.use_phf.then(|| {
+            serializations.iter().find(|(_, value, case_insensitive)| {
+                value == s
+                    || (*case_insensitive
+                        && (value.to_ascii_lowercase() == s || value.to_ascii_uppercase() == s))
+            })
+        });
+
+        phf_match
+            .flatten()
+            .or_else(|| {
+                serializations.iter().find(|(_, value, case_insensitive)| {
+                    if *case_insensitive {
+                        value.eq_ignore_ascii_case(s)
+                    } else {
+                        !type_properties.use_phf && value == s
+                    }
+                })
+            })
+            .map(|(ident, _, _)| *ident)
+    };
+
+    let mut errors: Option<syn::Error> = None;
+    for variant in variants {
+        let variant_properties = variant.get_variant_properties()?;
+        if !matches!(variant.fields, Fields::Unit)
+            || variant_properties.is_skipped(SkipTarget::Display)
+            || variant_properties.is_skipped(SkipTarget::Parse)
+        {
+            continue;
+        }
+
+        let ident = &variant.ident;
+        let name = variant_properties.get_preferred_name(type_properties)?;
+        let name = name.require_lit(USAGE)?.value();
+        let message = match parse(&name) {
+            Some(parsed) if parsed == ident => continue,
+            Some(parsed) => format!(
+                "Variant `{}` is displayed as \"{}\", which is parsed as `{}`",
+                ident, name, parsed
+            ),
+            None => format!(
+                "Variant `{}` is displayed as \"{}\", which doesn't parse",
+                ident, name
+            ),
+        };
+
+        let error = syn::Error::new_spanned(ident, message);
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    }
+
+    errors.map_or(Ok(()), Err)
+}
@@ -2,7 +2,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{Data, DeriveInput, Fields};
 
-use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties};
+use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties, SkipTarget};
 
 pub fn to_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
     let name = &ast.ident;
@@ -18,7 +18,7 @@ pub fn to_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         let ident = &variant.ident;
         let variant_properties = variant.get_variant_properties()?;
 
-        if variant_properties.disabled.is_some() {
+        if variant_properties.is_skipped(SkipTarget::Display) {
             continue;
         }
 
@@ -39,11 +39,7 @@ pub fn to_string_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
         }
 
         // Look at all the serialize attributes.
-        let output = variant_properties.get_preferred_name(
-            type_properties.case_style,
-            type_properties.prefix.as_ref(),
-            type_properties.suffix.as_ref(),
-        );
+        let output = variant_properties.get_preferred_name(&type_properties)?;
 
         let params = match variant.fields {
             Fields::Unit => quote! {},
@@ -0,0 +1,47 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields};
+
+use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties, SkipTarget};
+
+pub fn variant_iterator_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let gen = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = gen.split_for_impl();
+
+    let variants = match &ast.data {
+        Data::Enum(v) => &v.variants,
+        _ => return Err(non_enum_error()),
+    };
+
+    let type_properties = ast.get_type_properties()?;
+    let strum_module_path = type_properties.crate_module_path();
+
+    // Variants with fields are skipped, since we have no way of building their values.
+    let mut idents = Vec::new();
+    for variant in variants {
+        if variant
+            .get_variant_properties()?
+            .is_skipped(SkipTarget::Iter)
+        {
+            continue;
+        }
+
+        if let Fields::Unit = variant.fields {
+            idents.push(&variant.ident);
+        }
+    }
+    let count = idents.len();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #strum_module_path::VariantIterator for #name #ty_generics #where_clause {
+            type Iterator = ::core::array::IntoIter<Self, #count>;
+
+            #[inline]
+            fn iter() -> Self::Iterator {
+                ::core::iter::IntoIterator::into_iter([ #(#name::#idents),* ])
+            }
+        }
+    })
+}
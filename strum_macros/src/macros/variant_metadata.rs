@@ -0,0 +1,46 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput};
+
+use crate::helpers::{non_enum_error, HasStrumVariantProperties, HasTypeProperties, SkipTarget};
+
+pub fn variant_metadata_inner(ast: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &ast.ident;
+    let gen = &ast.generics;
+    let (impl_generics, ty_generics, where_clause) = gen.split_for_impl();
+
+    let variants = match &ast.data {
+        Data::Enum(v) => &v.variants,
+        _ => return Err(non_enum_error()),
+    };
+
+    let type_properties = ast.get_type_properties()?;
+    let strum_module_path = type_properties.crate_module_path();
+
+    let mut names = Vec::new();
+    let mut arms = Vec::new();
+    for variant in variants {
+        let ident = &variant.ident;
+        let props = variant.get_variant_properties()?;
+        let variant_name = props.get_preferred_name(&type_properties)?;
+        arms.push(quote! { #name::#ident { .. } => #variant_name });
+
+        if !props.is_skipped(SkipTarget::Names) {
+            names.push(variant_name);
+        }
+    }
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #strum_module_path::VariantMetadata for #name #ty_generics #where_clause {
+            const VARIANT_COUNT: usize = Self::VARIANT_NAMES.len();
+            const VARIANT_NAMES: &'static [&'static str] = &[ #(#names),* ];
+
+            fn variant_name(&self) -> &'static str {
+                match *self {
+                    #(#arms),*
+                }
+            }
+        }
+    })
+}
@@ -31,4 +31,31 @@ mod tests {
         use core::convert::TryFrom;
         assert_eq!(Color::Yellow, Color::try_from("yellow").unwrap());
     }
+
+    #[test]
+    fn blanket_into_enum_iterator_no_std() {
+        use gratte::IntoEnumIterator;
+
+        let mut iter = <(bool, Option<bool>)>::iter();
+        assert_eq!(6, iter.len());
+        assert_eq!(Some((false, None)), iter.next());
+        assert_eq!(Some((true, Some(true))), iter.next_back());
+    }
+
+    #[test]
+    fn enum_map_no_std() {
+        use gratte::{EnumArray, EnumIndex, EnumMap};
+
+        #[derive(Debug, Clone, Copy, PartialEq, EnumIndex, EnumArray)]
+        enum Light {
+            Red,
+            Amber,
+            Green,
+        }
+
+        let mut map = EnumMap::from_closure(|light: Light| light.index() as u8);
+        map[Light::Green] += 10;
+        assert_eq!(map.as_slice(), &[0, 1, 12]);
+        assert_eq!(map.iter().next_back(), Some((Light::Green, &12)));
+    }
 }
@@ -0,0 +1,108 @@
+use gratte::{EnumIter, IntoEnumIterator, VariantArray};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, EnumIter)]
+enum Level {
+    Low,
+    High,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, EnumIter)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[test]
+fn bool() {
+    assert_eq!(vec![false, true], bool::iter().collect::<Vec<_>>());
+    assert_eq!(&[false, true], <bool as VariantArray>::VARIANTS);
+}
+
+#[test]
+fn option() {
+    let values: Vec<_> = <Option<Level> as IntoEnumIterator>::iter().collect();
+    assert_eq!(vec![None, Some(Level::Low), Some(Level::High)], values);
+
+    let values: Vec<_> = <Option<Level> as IntoEnumIterator>::iter().rev().collect();
+    assert_eq!(vec![Some(Level::High), Some(Level::Low), None], values);
+
+    let mut iter = <Option<Level> as IntoEnumIterator>::iter();
+    assert_eq!(3, iter.len());
+    assert_eq!(Some(Some(Level::High)), iter.next_back());
+    assert_eq!(Some(None), iter.next());
+    assert_eq!(1, iter.len());
+    assert_eq!(Some(Some(Level::Low)), iter.next_back());
+    assert_eq!(None, iter.next());
+    assert_eq!(None, iter.next_back());
+}
+
+#[test]
+fn result() {
+    let values: Vec<_> = <Result<Level, bool> as IntoEnumIterator>::iter().collect();
+    let expected = vec![Ok(Level::Low), Ok(Level::High), Err(false), Err(true)];
+    assert_eq!(expected, values);
+
+    let mut iter = <Result<Level, bool> as IntoEnumIterator>::iter();
+    assert_eq!(Some(Err(true)), iter.next_back());
+    assert_eq!(Some(Ok(Level::Low)), iter.next());
+    assert_eq!(2, iter.len());
+    assert_eq!(2, iter.clone().count());
+    assert_eq!(
+        vec![Err(false), Ok(Level::High)],
+        iter.rev().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn tuples() {
+    let values: Vec<_> = <(Level, Color)>::iter().collect();
+    let expected = vec![
+        (Level::Low, Color::Red),
+        (Level::Low, Color::Green),
+        (Level::Low, Color::Blue),
+        (Level::High, Color::Red),
+        (Level::High, Color::Green),
+        (Level::High, Color::Blue),
+    ];
+    assert_eq!(expected, values);
+
+    let mut reversed: Vec<_> = <(Level, Color)>::iter().rev().collect();
+    reversed.reverse();
+    assert_eq!(expected, reversed);
+
+    assert_eq!(
+        vec![(false,), (true,)],
+        <(bool,)>::iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        2 * 3 * 3 * 2,
+        <(Level, Color, Option<Level>, bool)>::iter().len()
+    );
+    assert_eq!(
+        Some((Level::High, Color::Red, Some(Level::Low), true)),
+        <(Level, Color, Option<Level>, bool)>::iter().nth(18 + 2 + 1)
+    );
+}
+
+#[test]
+fn tuple_iter_nth() {
+    let mut iter = <(Level, Color)>::iter();
+    assert_eq!(Some((Level::High, Color::Green)), iter.nth(4));
+    assert_eq!(1, iter.len());
+    assert_eq!(None, iter.nth(1));
+    assert_eq!(None, iter.next_back());
+}
+
+fn count<T: IntoEnumIterator>() -> usize {
+    T::iter().len()
+}
+
+#[test]
+fn generic() {
+    assert_eq!(3, count::<Option<Level>>());
+    assert_eq!(5, count::<Result<Level, Color>>());
+    assert_eq!(20, count::<(bool, Option<(Level, bool)>, bool)>());
+}
@@ -0,0 +1,98 @@
+use gratte::EnumCycle;
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Eq, PartialEq, EnumCycle)]
+enum Season {
+    Spring,
+    Summer,
+    #[strum(skip(iter))]
+    Monsoon,
+    Autumn,
+    Winter,
+}
+
+#[test]
+fn next() {
+    assert_eq!(Some(Season::Summer), Season::Spring.next());
+    assert_eq!(Some(Season::Autumn), Season::Summer.next());
+    assert_eq!(Some(Season::Autumn), Season::Monsoon.next());
+    assert_eq!(Some(Season::Winter), Season::Autumn.next());
+    assert_eq!(None, Season::Winter.next());
+}
+
+#[test]
+fn prev() {
+    assert_eq!(None, Season::Spring.prev());
+    assert_eq!(Some(Season::Summer), Season::Monsoon.prev());
+    assert_eq!(Some(Season::Summer), Season::Autumn.prev());
+    assert_eq!(Some(Season::Autumn), Season::Winter.prev());
+}
+
+#[test]
+fn wrapping() {
+    assert_eq!(Season::Spring, Season::Winter.next_wrapping());
+    assert_eq!(Season::Winter, Season::Spring.prev_wrapping());
+    assert_eq!(Season::Autumn, Season::Monsoon.next_wrapping());
+    assert_eq!(Season::Summer, Season::Monsoon.prev_wrapping());
+}
+
+#[test]
+fn const_context() {
+    const NEXT: Option<Season> = Season::Spring.next();
+    const WRAPPED: Season = Season::Winter.next_wrapping();
+    assert_eq!(Some(Season::Summer), NEXT);
+    assert_eq!(Season::Spring, WRAPPED);
+}
+
+#[derive(Debug, Eq, PartialEq, EnumCycle)]
+enum Single {
+    Only,
+}
+
+#[test]
+fn single() {
+    assert_eq!(None, Single::Only.next());
+    assert_eq!(None, Single::Only.prev());
+    assert_eq!(Single::Only, Single::Only.next_wrapping());
+    assert_eq!(Single::Only, Single::Only.prev_wrapping());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumCycle)]
+enum Shape {
+    Point,
+    Circle {
+        radius: u32,
+    },
+    #[strum(catch_all)]
+    Other(u8),
+    Polygon(Vec<(i32, i32)>),
+}
+
+#[test]
+fn data_variants() {
+    assert_eq!(Some(Shape::Circle { radius: 0 }), Shape::Point.next());
+    assert_eq!(
+        Some(Shape::Polygon(Vec::new())),
+        Shape::Circle { radius: 5 }.next()
+    );
+    assert_eq!(Some(Shape::Polygon(Vec::new())), Shape::Other(3).next());
+    assert_eq!(Some(Shape::Circle { radius: 0 }), Shape::Other(3).prev());
+    assert_eq!(Shape::Point, Shape::Polygon(vec![(1, 2)]).next_wrapping());
+    assert_eq!(Shape::Polygon(Vec::new()), Shape::Point.prev_wrapping());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumCycle)]
+enum Slot<T> {
+    Empty,
+    Full(T),
+}
+
+#[test]
+fn generic_data_variants() {
+    assert_eq!(Some(Slot::Full(0u8)), Slot::Empty.next());
+    assert_eq!(
+        Slot::<String>::Empty,
+        Slot::Full(String::from("x")).next_wrapping()
+    );
+}
@@ -0,0 +1,119 @@
+use gratte::{EnumCount, EnumIndex, EnumIter, IntoEnumIterator};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Eq, PartialEq, EnumIndex, EnumIter, EnumCount)]
+enum Level {
+    Trace = 100,
+    Debug = 50,
+    #[strum(disabled)]
+    Verbose = 25,
+    Info = 10,
+    Error = 1,
+}
+
+#[test]
+fn index() {
+    assert_eq!(0, Level::Trace.index());
+    assert_eq!(1, Level::Debug.index());
+    assert_eq!(2, Level::Info.index());
+    assert_eq!(3, Level::Error.index());
+}
+
+#[test]
+#[should_panic(expected = "Level::Verbose has no index")]
+fn index_skipped() {
+    let _ = Level::Verbose.index();
+}
+
+#[test]
+fn try_index() {
+    assert_eq!(Some(0), Level::Trace.try_index());
+    assert_eq!(Some(3), Level::Error.try_index());
+    assert_eq!(None, Level::Verbose.try_index());
+}
+
+#[test]
+fn from_index() {
+    assert_eq!(Some(Level::Trace), Level::from_index(0));
+    assert_eq!(Some(Level::Info), Level::from_index(2));
+    assert_eq!(Some(Level::Error), Level::from_index(3));
+    assert_eq!(None, Level::from_index(4));
+}
+
+#[test]
+fn matches_iter() {
+    assert_eq!(Level::iter().len(), Level::COUNT);
+    assert_eq!(<Level as EnumCount>::COUNT, <Level as EnumIndex>::COUNT);
+    for (i, level) in Level::iter().enumerate() {
+        assert_eq!(i, level.index());
+        assert_eq!(Some(level), Level::from_index(i));
+    }
+}
+
+#[test]
+fn const_context() {
+    const INDEX: usize = Level::Info.index();
+    const LEVEL: Option<Level> = Level::from_index(1);
+    assert_eq!(2, INDEX);
+    assert_eq!(Some(Level::Debug), LEVEL);
+}
+
+#[derive(Debug, Eq, PartialEq, EnumIndex)]
+enum Shape {
+    Point,
+    Circle(u32),
+    Rect { w: u32, h: u32 },
+}
+
+#[test]
+fn with_fields() {
+    assert_eq!(1, Shape::Circle(7).index());
+    assert_eq!(2, Shape::Rect { w: 1, h: 2 }.index());
+    assert_eq!(Some(Shape::Rect { w: 0, h: 0 }), Shape::from_index(2));
+}
+
+fn generic_index<E: EnumIndex>(value: &E) -> (usize, usize) {
+    (value.index(), E::COUNT)
+}
+
+#[test]
+fn trait_impl() {
+    assert_eq!((1, 3), generic_index(&Shape::Circle(1)));
+    assert_eq!(Some(Shape::Point), <Shape as EnumIndex>::from_index(0));
+}
+
+#[derive(Debug, PartialEq, EnumIndex)]
+enum Wrapper<T> {
+    Empty,
+    Value(T),
+}
+
+#[test]
+fn generic_fields() {
+    assert_eq!(Some(Wrapper::Value(0u8)), Wrapper::from_index(1));
+    assert_eq!(
+        Some(Wrapper::Empty),
+        <Wrapper<String> as EnumIndex>::from_index(0)
+    );
+}
+
+#[derive(Debug, PartialEq, EnumIndex, EnumIter)]
+enum Frame {
+    Data,
+    #[strum(catch_all)]
+    Unknown(u8),
+    Close,
+}
+
+#[test]
+fn catch_all() {
+    assert_eq!(None, Frame::Unknown(7).try_index());
+    assert_eq!(Some(1), Frame::Close.try_index());
+    assert_eq!(Some(Frame::Close), Frame::from_index(1));
+    assert_eq!(2, Frame::COUNT);
+    assert_eq!(
+        vec![Frame::Data, Frame::Close],
+        Frame::iter().collect::<Vec<_>>()
+    );
+}
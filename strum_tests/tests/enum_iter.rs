@@ -224,3 +224,134 @@ fn enum_iter_option() {
 
     assert_eq!(expected, results);
 }
+
+#[test]
+fn iter_from_test() {
+    let results = Week::iter_from(Week::Thursday).collect::<Vec<_>>();
+    let expected = vec![Week::Thursday, Week::Friday, Week::Saturday];
+    assert_eq!(expected, results);
+    assert_eq!(3, Week::iter_from(Week::Thursday).len());
+    assert_eq!(
+        Some(Week::Saturday),
+        Week::iter_from(Week::Thursday).next_back()
+    );
+    assert_eq!(7, Week::iter_from(Week::Sunday).count());
+}
+
+#[test]
+fn iter_range_test() {
+    let results = Week::iter_range(Week::Tuesday..=Week::Friday).collect::<Vec<_>>();
+    let expected = vec![Week::Tuesday, Week::Wednesday, Week::Thursday, Week::Friday];
+    assert_eq!(expected, results);
+
+    let results = Week::iter_range(Week::Tuesday..Week::Friday)
+        .rev()
+        .collect::<Vec<_>>();
+    let expected = vec![Week::Thursday, Week::Wednesday, Week::Tuesday];
+    assert_eq!(expected, results);
+
+    assert_eq!(2, Week::iter_range(..Week::Tuesday).len());
+    assert_eq!(7, Week::iter_range(..).len());
+    assert_eq!(0, Week::iter_range(Week::Friday..Week::Monday).len());
+    assert_eq!(
+        None,
+        Week::iter_range(Week::Friday..Week::Monday).next_back()
+    );
+}
+
+#[derive(Debug, Eq, PartialEq, EnumIter)]
+enum Priority {
+    Low,
+    #[strum(disabled)]
+    Deprecated,
+    Normal,
+    #[strum(iter_fields)]
+    High(bool),
+    Urgent,
+}
+
+#[test]
+fn iter_range_skipped_and_fields_test() {
+    let results = Priority::iter_from(Priority::Deprecated).collect::<Vec<_>>();
+    let expected = vec![
+        Priority::Normal,
+        Priority::High(false),
+        Priority::High(true),
+        Priority::Urgent,
+    ];
+    assert_eq!(expected, results);
+
+    let results = Priority::iter_range(Priority::Low..=Priority::High(true)).collect::<Vec<_>>();
+    let expected = vec![
+        Priority::Low,
+        Priority::Normal,
+        Priority::High(false),
+        Priority::High(true),
+    ];
+    assert_eq!(expected, results);
+
+    let results = Priority::iter_range((
+        ::std::ops::Bound::Excluded(Priority::Normal),
+        ::std::ops::Bound::Unbounded,
+    ))
+    .collect::<Vec<_>>();
+    let expected = vec![
+        Priority::High(false),
+        Priority::High(true),
+        Priority::Urgent,
+    ];
+    assert_eq!(expected, results);
+}
+
+#[derive(Debug, PartialEq, EnumIter)]
+enum Token<'a> {
+    Ident(&'a str),
+    Comma,
+}
+
+#[test]
+fn lifetime_test() {
+    fn shorten<'a>(iter: TokenIter<'static>) -> TokenIter<'a> {
+        iter
+    }
+
+    let tokens: Vec<Token<'_>> = shorten(Token::iter()).collect();
+    assert_eq!(vec![Token::Ident(""), Token::Comma], tokens);
+}
+
+#[derive(Debug, PartialEq, EnumIter)]
+enum Packet<const N: usize> {
+    Data([u8; N]),
+    Ack,
+}
+
+#[test]
+fn const_generic_test() {
+    assert_eq!(
+        vec![Packet::Data([0; 4]), Packet::Ack],
+        Packet::<4>::iter().collect::<Vec<_>>()
+    );
+}
+
+#[derive(Debug, PartialEq, EnumIter)]
+enum Wrapper<T> {
+    Value(T),
+    #[strum(iter_fields)]
+    Pair(bool, T),
+    Empty,
+}
+
+#[test]
+fn type_generic_bounds_test() {
+    assert_eq!(
+        vec![
+            Wrapper::Value(false),
+            Wrapper::Pair(false, false),
+            Wrapper::Pair(false, true),
+            Wrapper::Pair(true, false),
+            Wrapper::Pair(true, true),
+            Wrapper::Empty,
+        ],
+        Wrapper::<bool>::iter().collect::<Vec<_>>()
+    );
+}
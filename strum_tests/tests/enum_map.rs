@@ -0,0 +1,157 @@
+use gratte::{EnumArray, EnumIndex, EnumMap};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIndex, EnumArray)]
+enum Color {
+    Red,
+    Yellow,
+    #[strum(disabled)]
+    Teal,
+    Green,
+    Blue,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIndex, EnumArray)]
+enum Size {
+    Small,
+    Large,
+}
+
+// Works for any enum, which is the point of `EnumMap` over `EnumTable`.
+fn sum<K: EnumArray<u32>>(map: &EnumMap<K, u32>) -> u32 {
+    map.values().sum()
+}
+
+#[test]
+fn construction() {
+    assert_eq!(EnumMap::<Color, u32>::default().into_array(), [0, 0, 0, 0]);
+    assert_eq!(EnumMap::<Color, u32>::filled(2).as_slice(), &[2, 2, 2, 2]);
+    let map = EnumMap::from_closure(|color: Color| color.index() as u32);
+    assert_eq!(map, EnumMap::from_array([0, 1, 2, 3]));
+    assert_eq!(map.len(), 4);
+    assert!(!map.is_empty());
+}
+
+#[test]
+fn generic_code() {
+    let colors: EnumMap<Color, u32> = EnumMap::from_array([1, 2, 3, 4]);
+    let sizes: EnumMap<Size, u32> = EnumMap::from_array([10, 20]);
+    assert_eq!(sum(&colors), 10);
+    assert_eq!(sum(&sizes), 30);
+}
+
+#[test]
+fn index() {
+    let mut map: EnumMap<Color, u32> = EnumMap::default();
+    map[Color::Green] = 3;
+    map[Color::Blue] += 4;
+    assert_eq!(map[Color::Green], 3);
+    assert_eq!(map.as_slice(), &[0, 0, 3, 4]);
+    map.as_mut_slice()[0] = 1;
+    assert_eq!(map[Color::Red], 1);
+}
+
+#[test]
+#[should_panic]
+fn index_disabled() {
+    let map: EnumMap<Color, u32> = EnumMap::default();
+    let _ = map[Color::Teal];
+}
+
+#[test]
+fn iter() {
+    let mut map: EnumMap<Color, u32> = EnumMap::from_array([1, 2, 3, 4]);
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        vec![
+            (Color::Red, &1),
+            (Color::Yellow, &2),
+            (Color::Green, &3),
+            (Color::Blue, &4)
+        ]
+    );
+    assert_eq!(map.iter().next_back(), Some((Color::Blue, &4)));
+    assert_eq!(map.iter().len(), 4);
+    assert_eq!(
+        map.keys().collect::<Vec<_>>(),
+        vec![Color::Red, Color::Yellow, Color::Green, Color::Blue]
+    );
+
+    for (color, value) in &mut map {
+        if color != Color::Green {
+            *value *= 10;
+        }
+    }
+    for value in map.values_mut() {
+        *value += 1;
+    }
+    assert_eq!(map.into_iter().collect::<Vec<_>>()[2], (Color::Green, 4));
+}
+
+#[test]
+fn from_iter() {
+    let map: EnumMap<Color, u32> = [(Color::Blue, 1), (Color::Red, 2), (Color::Blue, 3)]
+        .into_iter()
+        .collect();
+    assert_eq!(map.as_slice(), &[2, 0, 0, 3]);
+}
+
+#[test]
+fn transform() {
+    let map: EnumMap<Size, u32> = EnumMap::from_array([1, 2]);
+    let labels = map.transform(|size, value| format!("{:?}{}", size, value));
+    assert_eq!(
+        labels.into_array(),
+        ["Small1".to_string(), "Large2".to_string()]
+    );
+}
+
+#[test]
+fn option_all() {
+    let map: EnumMap<Size, Option<u32>> = EnumMap::from_array([Some(1), Some(2)]);
+    assert_eq!(map.all(), Some(EnumMap::from_array([1, 2])));
+
+    let map: EnumMap<Size, Option<u32>> = EnumMap::from_array([Some(1), None]);
+    assert_eq!(map.all(), None);
+}
+
+#[test]
+fn result_all_ok() {
+    let map: EnumMap<Size, Result<u32, u8>> = EnumMap::from_array([Ok(1), Ok(2)]);
+    assert_eq!(map.all_ok(), Ok(EnumMap::from_array([1, 2])));
+
+    let map: EnumMap<Color, Result<u32, u8>> = EnumMap::from_array([Ok(1), Err(2), Ok(3), Err(4)]);
+    assert_eq!(map.all_ok(), Err(2));
+}
+
+#[test]
+fn debug() {
+    let map: EnumMap<Size, u32> = EnumMap::from_array([1, 2]);
+    assert_eq!(format!("{:?}", map), "{Small: 1, Large: 2}");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIndex, EnumArray)]
+enum Status {
+    Ok,
+    #[strum(catch_all)]
+    Other(u16),
+}
+
+#[test]
+fn catch_all() {
+    let mut map = EnumMap::<Status, u32>::default();
+    map[Status::Ok] += 1;
+    assert_eq!(map.into_array(), [1]);
+    assert_eq!(map.keys().collect::<Vec<_>>(), vec![Status::Ok]);
+    assert_eq!(map.get(Status::Ok), Some(&1));
+    assert_eq!(map.get(Status::Other(404)), None);
+    assert_eq!(map.get_mut(Status::Other(404)), None);
+}
+
+#[test]
+#[should_panic(expected = "Status::Other has no index")]
+fn catch_all_index() {
+    let map = EnumMap::<Status, u32>::default();
+    let _ = map[Status::Other(404)];
+}
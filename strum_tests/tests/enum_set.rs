@@ -0,0 +1,177 @@
+use gratte::{EnumIndex, EnumSet, ParseError};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumSet, EnumIndex)]
+#[strum(serialize_all = "snake_case")]
+enum Perm {
+    Read,
+    Write,
+    #[strum(disabled)]
+    Admin,
+    #[strum(to_string = "exec", serialize = "x")]
+    Execute,
+    #[strum(ascii_case_insensitive)]
+    Delete,
+}
+
+const READ_WRITE: PermSet = PermSet::new().insert(Perm::Read).insert(Perm::Write);
+
+#[test]
+fn insert_remove_contains() {
+    assert!(PermSet::new().is_empty());
+    assert!(READ_WRITE.contains(Perm::Read));
+    assert!(!READ_WRITE.contains(Perm::Execute));
+    assert!(!READ_WRITE.contains(Perm::Admin));
+    assert_eq!(READ_WRITE.len(), 2);
+
+    let set = READ_WRITE.remove(Perm::Read).remove(Perm::Delete);
+    assert_eq!(set, PermSet::from(Perm::Write));
+    assert_eq!(READ_WRITE.remove(Perm::Admin), READ_WRITE);
+}
+
+#[test]
+#[should_panic]
+fn insert_disabled() {
+    let _ = PermSet::new().insert(Perm::Admin);
+}
+
+#[test]
+fn bits() {
+    assert_eq!(std::mem::size_of::<PermSet>(), 1);
+    assert_eq!(READ_WRITE.bits(), 0b0011);
+    assert_eq!(PermSet::ALL.bits(), 0b1111);
+    assert_eq!(PermSet::from_bits(0b1100).map(|set| set.len()), Some(2));
+    assert_eq!(PermSet::from_bits(0b10000), None);
+    assert_eq!(PermSet::from_bits_truncate(0b11000), Perm::Delete.into());
+    assert_eq!(
+        PermSet::from(Perm::Delete).bits(),
+        1 << Perm::Delete.index()
+    );
+}
+
+#[test]
+fn set_algebra() {
+    const EXECUTE_WRITE: PermSet = PermSet::new().insert(Perm::Execute).insert(Perm::Write);
+    const UNION: PermSet = READ_WRITE.union(EXECUTE_WRITE);
+    assert_eq!(UNION, PermSet::all().remove(Perm::Delete));
+    assert_eq!(READ_WRITE.intersection(EXECUTE_WRITE), Perm::Write.into());
+    assert_eq!(READ_WRITE.difference(EXECUTE_WRITE), Perm::Read.into());
+    assert_eq!(
+        READ_WRITE.symmetric_difference(EXECUTE_WRITE),
+        [Perm::Read, Perm::Execute].into_iter().collect()
+    );
+    assert_eq!(
+        READ_WRITE.complement(),
+        [Perm::Execute, Perm::Delete].into_iter().collect()
+    );
+    assert!(READ_WRITE.is_subset(&UNION));
+    assert!(UNION.is_superset(&EXECUTE_WRITE));
+    assert!(!READ_WRITE.is_disjoint(&EXECUTE_WRITE));
+    assert!(READ_WRITE.is_disjoint(&READ_WRITE.complement()));
+}
+
+#[test]
+fn operators() {
+    let execute = PermSet::from(Perm::Execute);
+    assert_eq!(READ_WRITE | execute, READ_WRITE.union(execute));
+    assert_eq!(READ_WRITE & execute, PermSet::EMPTY);
+    assert_eq!(READ_WRITE ^ READ_WRITE, PermSet::EMPTY);
+    assert_eq!(READ_WRITE - Perm::Read.into(), Perm::Write.into());
+    assert_eq!(!PermSet::ALL, PermSet::EMPTY);
+
+    let mut set = READ_WRITE;
+    set |= execute;
+    set -= Perm::Write.into();
+    assert_eq!(set, [Perm::Read, Perm::Execute].into_iter().collect());
+    set &= READ_WRITE;
+    set ^= Perm::Delete.into();
+    assert_eq!(set, [Perm::Read, Perm::Delete].into_iter().collect());
+}
+
+#[test]
+fn iter() {
+    let set = PermSet::ALL;
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        vec![Perm::Read, Perm::Write, Perm::Execute, Perm::Delete]
+    );
+    assert_eq!(
+        set.iter().rev().collect::<Vec<_>>(),
+        vec![Perm::Delete, Perm::Execute, Perm::Write, Perm::Read]
+    );
+    assert_eq!(set.iter().len(), 4);
+
+    let mut collected = PermSet::new();
+    collected.extend(&READ_WRITE);
+    assert_eq!(collected, READ_WRITE);
+    assert_eq!(READ_WRITE.into_iter().count(), 2);
+}
+
+#[test]
+fn display() {
+    assert_eq!(PermSet::new().to_string(), "");
+    assert_eq!(READ_WRITE.to_string(), "read|write");
+    assert_eq!(PermSet::ALL.to_string(), "read|write|exec|delete");
+    assert_eq!(format!("{:?}", READ_WRITE), "PermSet(read|write)");
+}
+
+#[test]
+fn from_str() {
+    assert_eq!("".parse(), Ok(PermSet::new()));
+    assert_eq!("read|write".parse(), Ok(READ_WRITE));
+    assert_eq!(" write | read ".parse(), Ok(READ_WRITE));
+    assert_eq!(
+        "x|exec|DELETE".parse(),
+        Ok([Perm::Execute, Perm::Delete]
+            .into_iter()
+            .collect::<PermSet>())
+    );
+    assert_eq!(
+        "read|admin".parse::<PermSet>(),
+        Err(ParseError::VariantNotFound)
+    );
+    assert_eq!(
+        "read||write".parse::<PermSet>(),
+        Err(ParseError::VariantNotFound)
+    );
+    assert_eq!(PermSet::ALL.to_string().parse(), Ok(PermSet::ALL));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumSet)]
+enum Flag {
+    #[strum(skip(parse))]
+    Hidden,
+    #[strum(skip(display))]
+    Internal,
+    Shown,
+}
+
+#[test]
+fn skipped_names_roundtrip() {
+    let all = FlagSet::ALL;
+    assert_eq!(all.to_string(), "Hidden|Internal|Shown");
+    assert_eq!(all.to_string().parse(), Ok(all));
+    assert_eq!("Internal".parse(), Ok(FlagSet::from(Flag::Internal)));
+}
+
+#[derive(EnumSet)]
+#[allow(dead_code)]
+enum Wide {
+    V0,
+    V1,
+    V2,
+    V3,
+    V4,
+    V5,
+    V6,
+    V7,
+    V8,
+}
+
+#[test]
+fn wide() {
+    assert_eq!(std::mem::size_of::<WideSet>(), 2);
+    assert_eq!(WideSet::ALL.bits(), 0x1ff);
+    assert_eq!(WideSet::ALL.iter().next_back().map(|v| v as u8), Some(8));
+}
@@ -79,3 +79,16 @@ fn variants_with_values() {
         ],
     );
 }
+
+// Weights only matter for random sampling, so they aren't checked without the `rand` feature.
+#[cfg(not(feature = "test_rand"))]
+#[test]
+fn zero_weights_without_rand() {
+    #[derive(Debug, PartialEq, VariantArray)]
+    enum Never {
+        #[strum(weight = 0)]
+        Picked,
+    }
+
+    assert_eq!(Never::VARIANTS, &[Never::Picked]);
+}
@@ -101,3 +101,404 @@ fn transform() {
     let all_two = ColorTable::filled(2);
     assert_eq!(all_two.transform(|_, n| *n * 2), ColorTable::filled(4));
 }
+
+#[derive(EnumTable)]
+#[allow(dead_code)]
+enum Token<'a> {
+    Comma,
+    Semicolon,
+    #[strum(disabled)]
+    Ident(&'a str),
+}
+
+#[test]
+fn lifetime() {
+    let mut counts = TokenTable::filled(0);
+    for token in [Token::Comma, Token::Semicolon, Token::Comma] {
+        counts[token] += 1;
+    }
+    assert_eq!(counts, TokenTable::new(2, 1));
+}
+
+#[derive(EnumTable)]
+#[allow(dead_code)]
+enum Wrapper<T, const N: usize> {
+    Empty,
+    Full,
+    #[strum(disabled)]
+    Value([T; N]),
+}
+
+#[test]
+fn generics() {
+    let table: WrapperTable<u8, 3, &str> =
+        WrapperTable::from_closure(|variant: Wrapper<u8, 3>| match variant {
+            Wrapper::Empty => "empty",
+            _ => "full",
+        });
+    assert_eq!(table[Wrapper::Empty], "empty");
+    assert_eq!(table.transform(|_, s| s.len()), WrapperTable::new(5, 4));
+    assert_eq!(
+        format!("{:?}", table),
+        "WrapperTable { _empty: \"empty\", _full: \"full\" }"
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumTable)]
+#[allow(dead_code)]
+enum Paint {
+    Red,
+    Yellow,
+    Green,
+    #[strum(disabled)]
+    Teal,
+    Blue,
+    #[strum(disabled)]
+    Indigo,
+}
+
+#[test]
+fn iter() {
+    let map = PaintTable::new(1, 2, 3, 4);
+    assert_eq!(
+        map.iter().collect::<Vec<_>>(),
+        vec![
+            (Paint::Red, &1),
+            (Paint::Yellow, &2),
+            (Paint::Green, &3),
+            (Paint::Blue, &4)
+        ]
+    );
+    assert_eq!(map.iter().next_back(), Some((Paint::Blue, &4)));
+    assert_eq!(map.iter().len(), 4);
+}
+
+#[test]
+fn iter_mut() {
+    let mut map = PaintTable::new(1, 2, 3, 4);
+    for (color, value) in map.iter_mut() {
+        if color != Paint::Green {
+            *value *= 10;
+        }
+    }
+    assert_eq!(map, PaintTable::new(10, 20, 3, 40));
+}
+
+#[test]
+fn keys_and_values() {
+    let mut map = PaintTable::new(1, 2, 3, 4);
+    assert_eq!(
+        map.keys().collect::<Vec<_>>(),
+        vec![Paint::Red, Paint::Yellow, Paint::Green, Paint::Blue]
+    );
+    assert_eq!(map.values().sum::<i32>(), 10);
+    for value in map.values_mut() {
+        *value += 1;
+    }
+    assert_eq!(map, PaintTable::new(2, 3, 4, 5));
+}
+
+#[test]
+fn into_iter() {
+    let mut map = PaintTable::new(1, 2, 3, 4);
+
+    let mut total = 0;
+    for (_, value) in &map {
+        total += value;
+    }
+    assert_eq!(total, 10);
+
+    for (_, value) in &mut map {
+        *value = -*value;
+    }
+
+    let owned: Vec<(Paint, String)> = PaintTable::from_closure(|color| format!("{:?}", color))
+        .into_iter()
+        .collect();
+    assert_eq!(owned[1], (Paint::Yellow, String::from("Yellow")));
+    assert_eq!(map, PaintTable::new(-1, -2, -3, -4));
+}
+
+#[test]
+fn from_iter() {
+    let map: PaintTable<u8> = [(Paint::Green, 3), (Paint::Red, 1), (Paint::Green, 5)]
+        .into_iter()
+        .collect();
+    assert_eq!(map, PaintTable::new(1, 0, 5, 0));
+
+    // disabled variants are ignored
+    let map: PaintTable<u8> = [(Paint::Teal, 3), (Paint::Blue, 4)].into_iter().collect();
+    assert_eq!(map, PaintTable::new(0, 0, 0, 4));
+
+    let roundtrip: PaintTable<u8> = map.clone().into_iter().collect();
+    assert_eq!(roundtrip, map);
+}
+
+static COSTS: PaintTable<u32> = PaintTable::from_array([10, 20, 30, 40]);
+const PRICES: PaintTable<u32> = PaintTable::new(1, 2, 3, 4);
+
+#[test]
+fn const_construction() {
+    assert_eq!(COSTS[Paint::Green], 30);
+    assert_eq!(PRICES[Paint::Blue], 4);
+    assert_eq!(COSTS, PaintTable::new(10, 20, 30, 40));
+}
+
+#[test]
+fn slices() {
+    let mut map = PaintTable::from_array([1, 2, 3, 4]);
+    assert_eq!(map.as_slice(), &[1, 2, 3, 4]);
+
+    map.as_mut_slice().reverse();
+    assert_eq!(map[Paint::Red], 4);
+    assert_eq!(map[Paint::Blue], 1);
+
+    map[Paint::Yellow] = 7;
+    assert_eq!(map.as_slice()[1], 7);
+    assert_eq!(
+        ::core::mem::size_of_val(&map),
+        4 * ::core::mem::size_of::<i32>()
+    );
+}
+
+#[test]
+fn map_and_zip() {
+    let counts = PaintTable::new(1, 2, 3, 4);
+    let names = counts.map(|color, count| format!("{:?}{}", color, count));
+    assert_eq!(names[Paint::Green], "Green3");
+
+    let pairs = PaintTable::new(1, 2, 3, 4).zip(PaintTable::new('a', 'b', 'c', 'd'));
+    assert_eq!(
+        pairs,
+        PaintTable::new((1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'))
+    );
+
+    let sums =
+        PaintTable::new(1, 2, 3, 4).zip_with(PaintTable::new(10, 20, 30, 40), |color, a, b| {
+            if color == Paint::Red {
+                0
+            } else {
+                a + b
+            }
+        });
+    assert_eq!(sums, PaintTable::new(0, 22, 33, 44));
+}
+
+#[test]
+fn fold_any_every() {
+    let map = PaintTable::new(1, 2, 3, 4);
+    assert_eq!(map.fold(0, |acc, _, value| acc * 10 + value), 1234);
+
+    let map = PaintTable::new(1, 2, 3, 4);
+    assert!(map.any(|color, &value| color == Paint::Green && value == 3));
+    assert!(!map.any(|_, &value| value > 4));
+    assert!(map.every(|_, &value| value > 0));
+    assert!(!map.every(|color, _| color != Paint::Blue));
+
+    let mut calls = 0;
+    assert!(!map.every(|_, &value| {
+        calls += 1;
+        value < 2
+    }));
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn max_by_key() {
+    let map = PaintTable::new(3, 7, 1, 7);
+    assert_eq!(map.max_by_key(|&value| value), Paint::Blue);
+    assert_eq!(
+        map.max_by_key(|&value| core::cmp::Reverse(value)),
+        Paint::Green
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let a = PaintTable::new(1, 2, 3, 4);
+    let b = PaintTable::new(10, 20, 30, 40);
+    assert_eq!(a.clone() + b.clone(), PaintTable::new(11, 22, 33, 44));
+    assert_eq!(b.clone() - a.clone(), PaintTable::new(9, 18, 27, 36));
+
+    let mut c = a.clone();
+    c += b.clone();
+    assert_eq!(c, PaintTable::new(11, 22, 33, 44));
+    c -= a;
+    assert_eq!(c, b);
+}
+
+#[derive(EnumTable)]
+#[allow(dead_code)]
+enum Event {
+    Click {
+        x: i32,
+        y: i32,
+    },
+    Key(char),
+    Focus,
+    #[strum(disabled)]
+    Unknown(String),
+}
+
+#[test]
+fn data_carrying_index() {
+    let mut counts: EventTable<u32> = EventTable::default();
+    let events = [
+        Event::Click { x: 1, y: 2 },
+        Event::Key('a'),
+        Event::Click { x: 3, y: 4 },
+        Event::Focus,
+    ];
+    for event in &events {
+        counts[event] += 1;
+    }
+    assert_eq!(counts[&Event::Click { x: 0, y: 0 }], 2);
+    assert_eq!(counts[EventTableKey::Key], 1);
+    assert_eq!(counts, EventTable::new(2, 1, 1));
+}
+
+#[test]
+#[should_panic]
+fn data_carrying_disabled() {
+    let _ = EventTable::<u8>::default()[&Event::Unknown(String::new())];
+}
+
+#[test]
+fn data_carrying_keys() {
+    let names = EventTable::from_closure(|kind| match kind {
+        EventTableKey::Click => "click",
+        EventTableKey::Key => "key",
+        _ => "other",
+    });
+    assert_eq!(names, EventTable::new("click", "key", "other"));
+    assert_eq!(
+        names.keys().collect::<Vec<_>>(),
+        vec![
+            EventTableKey::Click,
+            EventTableKey::Key,
+            EventTableKey::Focus
+        ]
+    );
+    let lengths: EventTable<usize> = names
+        .iter()
+        .map(|(kind, name)| (kind, name.len()))
+        .collect();
+    assert_eq!(lengths, EventTable::new(5, 3, 5));
+}
+
+#[derive(EnumTable)]
+#[allow(dead_code)]
+enum Shape<T> {
+    Circle(T),
+    Square { side: T },
+}
+
+#[test]
+fn data_carrying_generic() {
+    let table: ShapeTable<f32, &str> = ShapeTable::from_closure(|kind| match kind {
+        ShapeTableKey::Circle => "circle",
+        ShapeTableKey::Square => "square",
+    });
+    assert_eq!(table[&Shape::Square { side: 2.0 }], "square");
+    assert_eq!(table[ShapeTableKey::Circle], "circle");
+}
+
+#[test]
+fn get() {
+    let mut map = PaintTable::new(1, 2, 3, 4);
+    assert_eq!(map.get(Paint::Yellow), Some(&2));
+    assert_eq!(map.get(Paint::Teal), None);
+
+    *map.get_mut(Paint::Blue).unwrap() = 40;
+    assert_eq!(map.get_mut(Paint::Indigo), None);
+    assert_eq!(map, PaintTable::new(1, 2, 3, 40));
+}
+
+#[test]
+fn replace_and_try_set() {
+    let mut map = PaintTable::new(1, 2, 3, 4);
+    assert_eq!(map.replace(Paint::Red, 10), Ok(1));
+    assert_eq!(map.replace(Paint::Teal, 20), Err(20));
+    assert_eq!(map.try_set(Paint::Green, 30), Ok(()));
+    assert_eq!(map.try_set(Paint::Indigo, 50), Err(50));
+    assert_eq!(map, PaintTable::new(10, 2, 30, 4));
+}
+
+#[test]
+fn data_carrying_get() {
+    let mut counts: EventTable<u32> = EventTable::default();
+    for event in [Event::Key('a'), Event::Unknown(String::from("?"))] {
+        if let Some(count) = counts.get_mut(&event) {
+            *count += 1;
+        }
+    }
+    assert_eq!(counts, EventTable::new(0, 1, 0));
+    assert_eq!(counts.get(EventTableKey::Unknown), None);
+    assert_eq!(counts.get(&Event::Key('b')), Some(&1));
+    assert_eq!(counts.replace(&Event::Click { x: 0, y: 0 }, 5), Ok(0));
+    assert_eq!(counts.try_set(&Event::Unknown(String::new()), 7), Err(7));
+    assert_eq!(counts, EventTable::new(5, 1, 0));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumTable)]
+#[strum(table_disabled = "compile_error")]
+#[allow(dead_code)]
+enum Direction {
+    North,
+    #[strum(disabled)]
+    Up,
+    South,
+}
+
+#[test]
+fn restricted_keys() {
+    let mut map = DirectionTable::from_closure(|key| match key {
+        DirectionTableKey::North => 1,
+        DirectionTableKey::South => 2,
+    });
+    map[DirectionTableKey::South] += 10;
+    assert_eq!(map, DirectionTable::new(1, 12));
+    assert_eq!(
+        map.keys().collect::<Vec<_>>(),
+        vec![DirectionTableKey::North, DirectionTableKey::South]
+    );
+
+    assert_eq!(
+        DirectionTableKey::try_from(Direction::North),
+        Ok(DirectionTableKey::North)
+    );
+    assert_eq!(
+        DirectionTableKey::try_from(Direction::Up),
+        Err(Direction::Up)
+    );
+    assert_eq!(Direction::from(DirectionTableKey::South), Direction::South);
+
+    assert_eq!(map.get(Direction::South), Some(&12));
+    assert_eq!(map.get(Direction::Up), None);
+}
+
+#[derive(EnumTable, Debug, PartialEq)]
+#[strum(table_disabled = "compile_error")]
+#[allow(dead_code)]
+enum Request {
+    Get(String),
+    Put {
+        key: String,
+        value: u32,
+    },
+    #[strum(disabled)]
+    Unsupported,
+}
+
+#[test]
+fn data_carrying_restricted() {
+    let table = RequestTable::new("get", "put");
+    assert_eq!(table[RequestTableKey::Put], "put");
+    let put = Request::Put {
+        key: String::from("a"),
+        value: 1,
+    };
+    assert_eq!(RequestTableKey::try_from(&put), Ok(RequestTableKey::Put));
+    assert!(RequestTableKey::try_from(&Request::Unsupported).is_err());
+    assert_eq!(EventTableKey::from(&Event::Focus), EventTableKey::Focus);
+}
@@ -1,4 +1,4 @@
-use gratte::FromRepr;
+use gratte::{EnumCount, EnumIter, EnumString, FromRepr, FromReprError, IntoEnumIterator};
 
 mod core {} // ensure macros call `::core`
 
@@ -64,3 +64,158 @@ fn crate_module_path_test() {
     assert_eq!(Week::from_repr(6), Some(Week::Saturday));
     assert_eq!(Week::from_repr(7), None);
 }
+
+#[test]
+fn generics_test() {
+    #[derive(Debug, FromRepr, PartialEq)]
+    #[repr(u8)]
+    enum Token<'a, T, const N: usize> {
+        Ident(&'a str) = 1,
+        Number(T),
+        Bytes([u8; N]),
+        Eof,
+    }
+
+    assert_eq!(Token::<u32, 2>::from_repr(0), None);
+    assert_eq!(Token::<u32, 2>::from_repr(1), Some(Token::Ident("")));
+    assert_eq!(Token::<u32, 2>::from_repr(2), Some(Token::Number(0)));
+    assert_eq!(Token::<u32, 2>::from_repr(3), Some(Token::Bytes([0, 0])));
+    assert_eq!(Token::<u32, 2>::from_repr(4), Some(Token::Eof));
+}
+
+#[test]
+fn generics_without_default_test() {
+    struct NoDefault;
+
+    #[derive(FromRepr)]
+    #[allow(dead_code)]
+    enum Holder<T> {
+        Value(T),
+        Nothing,
+    }
+
+    // `from_repr` isn't available, but the enum can still be used.
+    let _ = Holder::Value(NoDefault);
+    assert!(matches!(Holder::<u8>::from_repr(1), Some(Holder::Nothing)));
+}
+
+#[test]
+fn try_from_test() {
+    assert_eq!(Week::try_from(7), Ok(Week::Friday));
+    assert_eq!(Week::try_from(6), Err(FromReprError(6)));
+    assert_eq!(
+        FromReprError(6u8).to_string(),
+        "No variant matches the discriminant 6"
+    );
+
+    fn parse_day(byte: u8) -> Result<Week, FromReprError<u8>> {
+        let day: Week = byte.try_into()?;
+        Ok(day)
+    }
+    assert_eq!(parse_day(8), Ok(Week::Saturday));
+    assert_eq!(parse_day(9), Err(FromReprError(9)));
+}
+
+#[test]
+fn to_repr_test() {
+    const FRIDAY: u8 = Week::Friday.to_repr();
+    assert_eq!(FRIDAY, 7);
+    assert_eq!(Week::Monday.to_repr(), 1);
+    assert_eq!(u8::from(Week::Saturday), 8);
+    let sunday: u8 = Week::Sunday.into();
+    assert_eq!(sunday, 0);
+}
+
+#[test]
+fn to_repr_data_test() {
+    #[derive(Debug, FromRepr, PartialEq)]
+    #[repr(u8)]
+    enum Token<'a, T, const N: usize> {
+        Ident(&'a str) = 1,
+        Number(T),
+        #[strum(skip(repr))]
+        Bytes([u8; N]),
+        Eof,
+    }
+
+    assert_eq!(Token::<u32, 2>::Ident("x").to_repr(), 1);
+    assert_eq!(Token::<u32, 2>::Number(5).to_repr(), 2);
+    assert_eq!(Token::<u32, 2>::Bytes([1, 2]).to_repr(), 3);
+    assert_eq!(Token::<u32, 2>::Eof.to_repr(), 4);
+    assert_eq!(Token::<u32, 2>::try_from(2), Ok(Token::Number(0)));
+    assert_eq!(Token::<u32, 2>::try_from(3), Err(FromReprError(3)));
+    assert_eq!(Token::<u32, 2>::try_from(4), Ok(Token::Eof));
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, FromRepr, EnumIter, EnumCount, EnumString)]
+#[repr(u8)]
+enum Opcode {
+    Ping = 1,
+    Pong,
+    #[strum(catch_all)]
+    Unknown(u8),
+    Close = 10,
+}
+
+#[test]
+fn catch_all_from_repr_test() {
+    const UNKNOWN: Option<Opcode> = Opcode::from_repr(7);
+    assert_eq!(UNKNOWN, Some(Opcode::Unknown(7)));
+    assert_eq!(Opcode::from_repr(2), Some(Opcode::Pong));
+    assert_eq!(Opcode::from_repr(10), Some(Opcode::Close));
+    assert_eq!(Opcode::from(0), Opcode::Unknown(0));
+
+    // Generic code relying on `TryFrom` gets an infallible conversion.
+    fn convert<T: TryFrom<u8>>(value: u8) -> Result<T, T::Error> {
+        T::try_from(value)
+    }
+    let converted: Result<Opcode, ::std::convert::Infallible> = convert(1);
+    assert_eq!(converted, Ok(Opcode::Ping));
+}
+
+#[test]
+fn catch_all_to_repr_test() {
+    assert_eq!(Opcode::Unknown(42).to_repr(), 42);
+    assert_eq!(Opcode::Close.to_repr(), 10);
+    assert_eq!(u8::from(Opcode::Unknown(3)), 3);
+    assert_eq!(u8::from(Opcode::Pong), 2);
+    for value in 0..=u8::MAX {
+        assert_eq!(Opcode::from(value).to_repr(), value);
+    }
+}
+
+#[test]
+fn catch_all_iter_test() {
+    assert_eq!(
+        Opcode::iter().collect::<Vec<_>>(),
+        vec![Opcode::Ping, Opcode::Pong, Opcode::Close]
+    );
+    assert_eq!(Opcode::COUNT, 3);
+    gratte::testing::assert_count_consistent::<Opcode>();
+}
+
+#[test]
+fn catch_all_from_str_test() {
+    assert_eq!("Pong".parse(), Ok(Opcode::Pong));
+    assert_eq!("7".parse(), Ok(Opcode::Unknown(7)));
+    assert_eq!(
+        "Unknown".parse::<Opcode>(),
+        Err(gratte::ParseError::VariantNotFound)
+    );
+}
+
+#[test]
+fn catch_all_named_field_test() {
+    #[derive(Debug, PartialEq, FromRepr)]
+    enum Status {
+        Ok,
+        #[strum(catch_all)]
+        Other {
+            code: usize,
+        },
+    }
+
+    assert_eq!(Status::from_repr(0), Some(Status::Ok));
+    assert_eq!(Status::from(5), Status::Other { code: 5 });
+    assert_eq!(Status::Other { code: 9 }.to_repr(), 9);
+}
@@ -335,3 +335,23 @@ fn case_custom_infallible_parsing_with_default_no_err_fn() {
 
     assert!(r.is_ok());
 }
+
+#[derive(Debug, Eq, PartialEq, EnumString)]
+enum Header {
+    Accept,
+    #[strum(catch_all)]
+    Other {
+        name: String,
+    },
+}
+
+#[test]
+fn catch_all() {
+    assert_eq!(Header::from_str("Accept"), Ok(Header::Accept));
+    assert_eq!(
+        Header::from_str("X-Custom"),
+        Ok(Header::Other {
+            name: "X-Custom".into()
+        })
+    );
+}
@@ -0,0 +1,123 @@
+use gratte::{EnumCount, EnumIter, IntoEnumIterator};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, EnumIter)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[derive(Debug, Eq, PartialEq, EnumIter)]
+enum Shape {
+    Point,
+    #[strum(iter_fields)]
+    Circle(Color),
+    #[strum(iter_fields)]
+    Square {
+        color: Color,
+        filled: bool,
+    },
+    #[strum(iter_fields)]
+    Line(#[strum(iter_values = [1, 2, 4])] u32),
+    Empty(u8),
+}
+
+#[test]
+fn iter_fields() {
+    let shapes: Vec<_> = Shape::iter().collect();
+    let expected = vec![
+        Shape::Point,
+        Shape::Circle(Color::Red),
+        Shape::Circle(Color::Green),
+        Shape::Circle(Color::Blue),
+        Shape::Square {
+            color: Color::Red,
+            filled: false,
+        },
+        Shape::Square {
+            color: Color::Red,
+            filled: true,
+        },
+        Shape::Square {
+            color: Color::Green,
+            filled: false,
+        },
+        Shape::Square {
+            color: Color::Green,
+            filled: true,
+        },
+        Shape::Square {
+            color: Color::Blue,
+            filled: false,
+        },
+        Shape::Square {
+            color: Color::Blue,
+            filled: true,
+        },
+        Shape::Line(1),
+        Shape::Line(2),
+        Shape::Line(4),
+        Shape::Empty(0),
+    ];
+    assert_eq!(expected, shapes);
+}
+
+#[test]
+fn exact_size() {
+    let mut iter = Shape::iter();
+    assert_eq!(14, iter.len());
+    iter.next();
+    assert_eq!(13, iter.len());
+    iter.next_back();
+    assert_eq!(12, iter.len());
+}
+
+#[test]
+fn rev() {
+    let forward: Vec<_> = Shape::iter().collect();
+    let mut backward: Vec<_> = Shape::iter().rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+    assert_eq!(Some(Shape::Line(4)), Shape::iter().nth_back(1));
+}
+
+#[derive(Debug, Eq, PartialEq, EnumIter, EnumCount)]
+#[strum(iter_fields)]
+enum Toggle {
+    Off,
+    On(bool, bool),
+    #[allow(dead_code)]
+    #[strum(disabled)]
+    Broken(String),
+}
+
+#[test]
+fn enum_level() {
+    let toggles: Vec<_> = Toggle::iter().collect();
+    let expected = vec![
+        Toggle::Off,
+        Toggle::On(false, false),
+        Toggle::On(false, true),
+        Toggle::On(true, false),
+        Toggle::On(true, true),
+    ];
+    assert_eq!(expected, toggles);
+    assert_eq!(2, Toggle::COUNT);
+}
+
+#[derive(Debug, Eq, PartialEq, EnumIter)]
+enum Nested {
+    #[strum(iter_fields)]
+    Shape(Toggle),
+}
+
+#[test]
+fn nested() {
+    assert_eq!(5, Nested::iter().len());
+    assert_eq!(
+        Some(Nested::Shape(Toggle::On(true, false))),
+        Nested::iter().nth(3)
+    );
+}
@@ -0,0 +1,148 @@
+use gratte::{EnumIter, EnumMessage, EnumProperty, EnumString, IntoEnumIterator, VariantNames};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, EnumMessage, EnumProperty, VariantNames)]
+#[strum(serialize_all = "kebab-case")]
+enum Unit {
+    #[strum(message = "Meters", props(symbol = "m"))]
+    Meter,
+    #[strum(serialize = "km", props(symbol = "km"))]
+    Kilometer,
+    #[strum(disabled)]
+    #[allow(dead_code)]
+    Furlong,
+    #[strum(message = "Parsecs")]
+    Parsec,
+}
+
+#[test]
+fn iter_with_names() {
+    assert_eq!(
+        vec![
+            (Unit::Meter, Some("meter")),
+            (Unit::Kilometer, Some("km")),
+            (Unit::Parsec, Some("parsec")),
+        ],
+        Unit::iter_with_names().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn iter_with_names_matches_variants() {
+    let names: Vec<_> = Unit::iter_with_names()
+        .filter_map(|(_, name)| name)
+        .collect();
+    assert_eq!(Unit::VARIANTS, names.as_slice());
+}
+
+#[test]
+fn iter_with_names_rev() {
+    let mut iter = Unit::iter_with_names();
+    assert_eq!(Some((Unit::Parsec, Some("parsec"))), iter.next_back());
+    assert_eq!(Some((Unit::Meter, Some("meter"))), iter.next());
+    assert_eq!(1, iter.len());
+}
+
+#[test]
+fn iter_with_messages() {
+    assert_eq!(
+        vec![
+            (Unit::Meter, Some("Meters")),
+            (Unit::Kilometer, None),
+            (Unit::Parsec, Some("Parsecs")),
+        ],
+        Unit::iter_with_messages().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn iter_with_props() {
+    assert_eq!(
+        vec![
+            (Unit::Meter, Some("m")),
+            (Unit::Kilometer, Some("km")),
+            (Unit::Parsec, None),
+        ],
+        Unit::iter_with_props("symbol").collect::<Vec<_>>()
+    );
+    assert_eq!(
+        Some((Unit::Parsec, None)),
+        Unit::iter_with_props("symbol").nth(2)
+    );
+    assert_eq!(
+        Some((Unit::Parsec, None)),
+        Unit::iter_with_props("symbol").next_back()
+    );
+}
+
+#[derive(Debug, PartialEq, EnumIter)]
+enum Shape {
+    #[strum(to_string = "circle")]
+    Circle { radius: u32 },
+    #[strum(iter_fields)]
+    Square(bool),
+}
+
+#[test]
+fn iter_with_names_fields() {
+    assert_eq!(
+        vec![
+            (Shape::Circle { radius: 0 }, Some("circle")),
+            (Shape::Square(false), Some("Square")),
+            (Shape::Square(true), Some("Square")),
+        ],
+        Shape::iter_with_names().collect::<Vec<_>>()
+    );
+}
+
+#[derive(Debug, PartialEq, EnumIter)]
+enum Generic<T: Default> {
+    A(T),
+    B,
+}
+
+#[test]
+fn iter_with_names_generic() {
+    assert_eq!(
+        vec![(Generic::A(0u8), Some("A")), (Generic::B, Some("B"))],
+        Generic::<u8>::iter_with_names().collect::<Vec<_>>()
+    );
+}
+
+#[derive(Debug, PartialEq, EnumIter, VariantNames)]
+enum Hidden {
+    Shown,
+    #[strum(skip(names))]
+    Unnamed,
+    Last,
+}
+
+#[test]
+fn iter_with_names_skip_names() {
+    assert_eq!(3, Hidden::iter_with_names().len());
+    assert_eq!(
+        vec![
+            (Hidden::Shown, Some("Shown")),
+            (Hidden::Unnamed, None),
+            (Hidden::Last, Some("Last"))
+        ],
+        Hidden::iter_with_names().collect::<Vec<_>>()
+    );
+    assert_eq!(["Shown", "Last"], Hidden::VARIANTS);
+}
+
+const SHORT: &str = "s";
+
+// Names may be non-literal, in which case `iter_with_names` isn't generated.
+#[derive(Debug, PartialEq, EnumIter, EnumString)]
+enum Unnamed {
+    #[strum(serialize = SHORT, serialize = concat!("lo", "ng"))]
+    Value,
+}
+
+#[test]
+fn non_literal_names() {
+    assert_eq!(vec![Unnamed::Value], Unnamed::iter().collect::<Vec<_>>());
+    assert_eq!(Ok(Unnamed::Value), "long".parse());
+}
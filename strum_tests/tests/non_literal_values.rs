@@ -0,0 +1,115 @@
+use gratte::{
+    AsRefStr, Display, EnumIter, EnumMessage, EnumString, IntoEnumIterator, IntoStaticStr,
+    VariantNames,
+};
+use std::str::FromStr;
+
+mod core {} // ensure macros call `::core`
+
+pub const API_PREFIX: &str = "v2_";
+const RED: &str = "red";
+const RED_MESSAGE: &str = "The color red";
+
+mod names {
+    pub const YELLOW: &str = "yellow";
+}
+
+#[derive(
+    Debug, Eq, PartialEq, EnumString, Display, AsRefStr, IntoStaticStr, VariantNames, EnumMessage,
+)]
+enum Color {
+    #[strum(serialize = RED, message = RED_MESSAGE)]
+    Red,
+    #[strum(serialize = "b", to_string = concat!("bl", "ue"))]
+    Blue { hue: usize },
+    #[strum(to_string = names::YELLOW, detailed_message = stringify!(Yellow))]
+    Yellow,
+    #[strum(serialize = "purple", ascii_case_insensitive)]
+    Purple,
+    #[strum(to_string = "dark_red", serialize = concat!("crim", "son"))]
+    DarkRed,
+}
+
+#[test]
+fn non_literal_display() {
+    assert_eq!("red", Color::Red.to_string());
+    assert_eq!("blue", Color::Blue { hue: 0 }.to_string());
+    assert_eq!("yellow", Color::Yellow.as_ref());
+    assert_eq!("yellow", <&'static str>::from(Color::Yellow));
+    assert_eq!(
+        ["red", "blue", "yellow", "purple", "dark_red"],
+        Color::VARIANTS
+    );
+}
+
+#[test]
+fn non_literal_from_str() {
+    assert_eq!(Color::Red, Color::from_str("red").unwrap());
+    assert_eq!(Color::Blue { hue: 0 }, Color::from_str("blue").unwrap());
+    assert_eq!(Color::Blue { hue: 0 }, Color::from_str("b").unwrap());
+    assert_eq!(Color::Yellow, Color::from_str("yellow").unwrap());
+    assert_eq!(Color::Purple, Color::from_str("PURPLE").unwrap());
+    assert_eq!(Color::DarkRed, Color::from_str("dark_red").unwrap());
+    assert_eq!(Color::DarkRed, Color::from_str("crimson").unwrap());
+}
+
+#[test]
+fn non_literal_messages() {
+    assert_eq!(Some("The color red"), Color::Red.get_message());
+    assert_eq!(Some("Yellow"), Color::Yellow.get_detailed_message());
+    assert_eq!(["blue"], &Color::Blue { hue: 0 }.get_serializations()[1..]);
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString, Display, AsRefStr, IntoStaticStr, VariantNames)]
+#[strum(prefix = API_PREFIX, suffix = concat!(".", "json"), const_into_str)]
+enum Endpoint {
+    Users,
+    #[strum(to_string = "group-list")]
+    Groups,
+    #[strum(serialize = RED)]
+    Colors,
+}
+
+#[test]
+fn non_literal_affixes() {
+    assert_eq!("v2_Users.json", Endpoint::Users.to_string());
+    assert_eq!("v2_group-list.json", Endpoint::Groups.as_ref());
+    assert_eq!("v2_red.json", Endpoint::Colors.into_str());
+    assert_eq!(
+        ["v2_Users.json", "v2_group-list.json", "v2_red.json"],
+        Endpoint::VARIANTS
+    );
+    assert_eq!(Endpoint::Colors, Endpoint::from_str("red").unwrap());
+}
+
+#[test]
+fn non_literal_affixes_in_const() {
+    const USERS: &str = Endpoint::Users.into_str();
+    assert_eq!("v2_Users.json", USERS);
+}
+
+// Names are only needed by `iter_with_names`, which is left out, and `table_serde`, so several
+// non-literal `serialize` values are accepted otherwise.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, EnumString, EnumIter, gratte::EnumTable)]
+enum Shade {
+    #[strum(serialize = RED, serialize = concat!("crim", "son"))]
+    Red,
+    #[strum(serialize = names::YELLOW, serialize = "gold")]
+    Yellow,
+}
+
+#[test]
+fn non_literal_names_not_needed() {
+    assert_eq!(Ok(Shade::Red), Shade::from_str("crimson"));
+    assert_eq!(
+        vec![Shade::Red, Shade::Yellow],
+        Shade::iter().collect::<Vec<_>>()
+    );
+}
+
+#[cfg(not(feature = "test_serde"))]
+#[test]
+fn non_literal_names_table() {
+    let table = ShadeTable::new(1, 2);
+    assert_eq!(2, table[Shade::Yellow]);
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "test_rand")]
+
+use std::collections::HashMap;
+
+use gratte::{EnumIter, VariantArray};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+mod core {} // ensure macros call `::core`
+
+const SAMPLES: usize = 10_000;
+
+fn rng() -> SmallRng {
+    SmallRng::seed_from_u64(42)
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumIter, VariantArray)]
+enum Suit {
+    Hearts,
+    Diamonds,
+    Clubs,
+    Spades,
+    #[strum(disabled)]
+    Joker,
+}
+
+#[test]
+fn uniform() {
+    let mut rng = rng();
+    let mut counts = HashMap::new();
+    for _ in 0..SAMPLES {
+        *counts.entry(rng.random::<Suit>()).or_insert(0) += 1;
+    }
+
+    assert_eq!(4, counts.len());
+    assert!(!counts.contains_key(&Suit::Joker));
+    for count in counts.values() {
+        assert!((2000..3000).contains(count), "{:?}", counts);
+    }
+}
+
+#[test]
+fn uniform_variant_array() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        assert_ne!(Some(&Suit::Joker), Suit::random(&mut rng));
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumIter, VariantArray)]
+enum Outcome {
+    #[strum(weight = 9)]
+    Success,
+    Failure,
+    #[strum(weight = 0)]
+    Impossible,
+}
+
+#[test]
+fn weighted() {
+    let mut rng = rng();
+    let mut counts = HashMap::new();
+    for _ in 0..SAMPLES {
+        *counts.entry(rng.random::<Outcome>()).or_insert(0) += 1;
+    }
+    assert!((8500..9500).contains(&counts[&Outcome::Success]));
+    assert!(!counts.contains_key(&Outcome::Impossible));
+
+    let mut counts = HashMap::new();
+    for _ in 0..SAMPLES {
+        *counts.entry(Outcome::random(&mut rng)).or_insert(0) += 1;
+    }
+    assert!((8500..9500).contains(&counts[&Some(&Outcome::Success)]));
+    assert!(!counts.contains_key(&Some(&Outcome::Impossible)));
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, VariantArray)]
+enum Coin {
+    Heads,
+    #[strum(weight = 3)]
+    Tails,
+}
+
+#[test]
+fn variant_array_only() {
+    let mut rng = rng();
+    let mut counts = HashMap::new();
+    for _ in 0..SAMPLES {
+        *counts.entry(rng.random::<Coin>()).or_insert(0) += 1;
+    }
+    assert!((7000..8000).contains(&counts[&Coin::Tails]));
+    assert!(counts.contains_key(&Coin::Heads));
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumIter)]
+enum Shape {
+    Point,
+    Circle(u8),
+    #[strum(iter_fields, weight = 2)]
+    Square(bool),
+}
+
+#[test]
+fn data_carrying() {
+    let mut rng = rng();
+    let mut counts = HashMap::new();
+    for _ in 0..SAMPLES {
+        *counts.entry(rng.random::<Shape>()).or_insert(0) += 1;
+    }
+
+    assert_eq!(4, counts.len());
+    assert!(counts.contains_key(&Shape::Circle(0)));
+    assert!((2000..3000).contains(&counts[&Shape::Square(true)]));
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumIter, VariantArray)]
+enum Never {}
+
+#[test]
+fn empty_variant_array() {
+    assert_eq!(None, Never::random(&mut rng()));
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumIter)]
+enum Maybe {
+    #[strum(iter_fields, weight = 100)]
+    Impossible(Never),
+    Nothing,
+}
+
+#[test]
+fn empty_iter_fields() {
+    let mut rng = rng();
+    for _ in 0..100 {
+        assert_eq!(Maybe::Nothing, rng.random::<Maybe>());
+    }
+}
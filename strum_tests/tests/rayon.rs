@@ -0,0 +1,77 @@
+#![cfg(feature = "test_rayon")]
+
+use gratte::{EnumIter, IntoEnumIterator};
+use rayon::prelude::*;
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, EnumIter)]
+enum Region {
+    Americas,
+    Europe,
+    #[strum(disabled)]
+    Antarctica,
+    Asia,
+    Africa,
+    Oceania,
+}
+
+#[test]
+fn par_iter() {
+    let regions: Vec<_> = Region::par_iter().collect();
+    assert_eq!(Region::iter().collect::<Vec<_>>(), regions);
+    assert_eq!(5, Region::par_iter().len());
+    assert!(!Region::par_iter().any(|region| region == Region::Antarctica));
+}
+
+#[test]
+fn into_par_iter() {
+    let names: Vec<_> = Region::iter()
+        .into_par_iter()
+        .map(|region| format!("{:?}", region))
+        .collect();
+    assert_eq!(
+        vec!["Americas", "Europe", "Asia", "Africa", "Oceania"],
+        names
+    );
+}
+
+#[test]
+fn indexed() {
+    let regions: Vec<_> = Region::iter()
+        .into_par_iter()
+        .with_min_len(1)
+        .with_max_len(1)
+        .rev()
+        .skip(1)
+        .collect();
+    let expected = vec![
+        Region::Africa,
+        Region::Asia,
+        Region::Europe,
+        Region::Americas,
+    ];
+    assert_eq!(expected, regions);
+
+    let (left, right): (Vec<_>, Vec<_>) = Region::par_iter()
+        .enumerate()
+        .partition(|(i, _)| i % 2 == 0);
+    assert_eq!(3, left.len());
+    assert_eq!(2, right.len());
+}
+
+#[derive(Debug, Eq, PartialEq, EnumIter)]
+enum Shape<T: Default> {
+    #[strum(iter_fields)]
+    Dot(bool),
+    Other(T),
+}
+
+#[test]
+fn generic() {
+    let shapes: Vec<Shape<u8>> = Shape::iter().into_par_iter().collect();
+    assert_eq!(
+        vec![Shape::Dot(false), Shape::Dot(true), Shape::Other(0)],
+        shapes
+    );
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "test_serde")]
+
+use gratte::EnumTable;
+use serde::{Deserialize, Serialize};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Config {
+    enabled: bool,
+}
+
+#[derive(EnumTable)]
+#[strum(table_serde, serialize_all = "snake_case")]
+#[allow(dead_code)]
+enum Color {
+    Red,
+    #[strum(serialize = "lime")]
+    Green,
+    #[strum(disabled)]
+    Teal,
+    DarkBlue,
+}
+
+#[test]
+fn serialize() {
+    let table = ColorTable::new(
+        Config { enabled: true },
+        Config { enabled: false },
+        Config { enabled: true },
+    );
+    assert_eq!(
+        serde_json::to_string(&table).unwrap(),
+        r#"{"red":{"enabled":true},"lime":{"enabled":false},"dark_blue":{"enabled":true}}"#
+    );
+}
+
+#[test]
+fn deserialize() {
+    let table: ColorTable<u8> =
+        serde_json::from_str(r#"{"dark_blue": 3, "red": 1, "lime": 2}"#).unwrap();
+    assert_eq!(table, ColorTable::new(1, 2, 3));
+
+    let roundtrip: ColorTable<u8> =
+        serde_json::from_str(&serde_json::to_string(&table).unwrap()).unwrap();
+    assert_eq!(roundtrip, table);
+}
+
+#[test]
+fn deserialize_owned_keys() {
+    // Escaped keys can't be borrowed from the input.
+    let table: ColorTable<u8> =
+        serde_json::from_str(r#"{"\u0072ed": 1, "lime": 2, "dark_blue": 3}"#).unwrap();
+    assert_eq!(table, ColorTable::new(1, 2, 3));
+
+    let table: ColorTable<u8> =
+        serde_json::from_value(serde_json::json!({"red": 1, "lime": 2, "dark_blue": 3})).unwrap();
+    assert_eq!(table, ColorTable::new(1, 2, 3));
+}
+
+#[test]
+fn deserialize_errors() {
+    let err = serde_json::from_str::<ColorTable<u8>>(r#"{"red": 1, "lime": 2}"#).unwrap_err();
+    assert!(
+        err.to_string().contains("missing field `dark_blue`"),
+        "{}",
+        err
+    );
+
+    let err = serde_json::from_str::<ColorTable<u8>>(r#"{"red": 1, "teal": 2}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown field `teal`"), "{}", err);
+
+    let err = serde_json::from_str::<ColorTable<u8>>(r#"{"red": 1, "red": 2}"#).unwrap_err();
+    assert!(err.to_string().contains("duplicate field `red`"), "{}", err);
+
+    let err = serde_json::from_str::<ColorTable<u8>>("[1, 2, 3]").unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("a map from the variants of `Color` to values"),
+        "{}",
+        err
+    );
+}
+
+#[derive(EnumTable)]
+#[strum(table_serde_default)]
+#[allow(dead_code)]
+enum Level<T> {
+    Low,
+    High,
+    #[strum(disabled)]
+    Custom(T),
+}
+
+#[test]
+fn deserialize_default() {
+    let table: LevelTable<u8, Vec<u32>> = serde_json::from_str(r#"{"High": [1, 2]}"#).unwrap();
+    assert_eq!(table, LevelTable::new(vec![], vec![1, 2]));
+}
+
+#[test]
+fn non_self_describing() {
+    let table = ColorTable::new(1u8, 2, 3);
+    let bytes = bincode::serialize(&table).unwrap();
+    assert_eq!(
+        bincode::deserialize::<ColorTable<u8>>(&bytes).unwrap(),
+        table
+    );
+}
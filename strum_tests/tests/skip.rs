@@ -0,0 +1,107 @@
+use gratte::{
+    AsRefStr, EnumCount, EnumIs, EnumIter, EnumMessage, EnumProperty, EnumString, FromRepr,
+    IntoEnumIterator, VariantArray, VariantNames,
+};
+use std::str::FromStr;
+
+mod core {} // ensure macros call `::core`
+
+#[derive(
+    Debug,
+    Eq,
+    PartialEq,
+    EnumString,
+    gratte::Display,
+    AsRefStr,
+    EnumIter,
+    VariantArray,
+    VariantNames,
+    EnumCount,
+    EnumMessage,
+    EnumProperty,
+    EnumIs,
+    FromRepr,
+)]
+enum Level {
+    #[strum(message = "low", props(value = 1))]
+    Low,
+    #[strum(message = "high", props(value = 3))]
+    High,
+    #[strum(skip(iter, names), message = "medium", props(value = 2))]
+    Medium,
+    #[strum(skip(parse, messages, props, is, repr))]
+    Unknown,
+    #[strum(disabled)]
+    Removed,
+}
+
+#[test]
+fn skip_iter() {
+    assert_eq!(
+        vec![Level::Low, Level::High, Level::Unknown],
+        Level::iter().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        &[Level::Low, Level::High, Level::Unknown],
+        <Level as VariantArray>::VARIANTS
+    );
+    assert_eq!(3, Level::COUNT);
+}
+
+#[test]
+fn skip_names() {
+    assert_eq!(
+        ["Low", "High", "Unknown"],
+        <Level as VariantNames>::VARIANTS
+    );
+}
+
+#[test]
+fn skip_parse() {
+    assert_eq!(Level::Medium, Level::from_str("Medium").unwrap());
+    assert!(Level::from_str("Unknown").is_err());
+    assert!(Level::from_str("Removed").is_err());
+}
+
+#[test]
+fn skip_display() {
+    assert_eq!("Medium", Level::Medium.to_string());
+    assert_eq!("Unknown", Level::Unknown.as_ref());
+}
+
+#[test]
+#[should_panic]
+fn skip_display_disabled() {
+    let _ = Level::Removed.to_string();
+}
+
+#[test]
+fn skip_messages_and_props() {
+    assert_eq!(Some("medium"), Level::Medium.get_message());
+    assert_eq!(Some(2), Level::Medium.get_int("value"));
+    assert_eq!(None, Level::Unknown.get_message());
+    assert_eq!(None, Level::Unknown.get_int("value"));
+    assert_eq!(["Unknown"], Level::Unknown.get_serializations());
+}
+
+#[test]
+fn skip_is_and_repr() {
+    assert!(Level::Medium.is_medium());
+    assert_eq!(Some(Level::Medium), Level::from_repr(2));
+    assert_eq!(None, Level::from_repr(3));
+    assert_eq!(None, Level::from_repr(4));
+}
+
+#[derive(Debug, Eq, PartialEq, VariantArray)]
+enum Shape {
+    Square,
+    Circle,
+    #[strum(skip(iter))]
+    #[allow(dead_code)]
+    Custom(u32),
+}
+
+#[test]
+fn skip_non_unit_variant_array() {
+    assert_eq!(&[Shape::Square, Shape::Circle], Shape::VARIANTS);
+}
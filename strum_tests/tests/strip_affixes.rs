@@ -0,0 +1,72 @@
+use gratte::{AsRefStr, Display, EnumMessage, EnumString, IntoStaticStr, VariantNames};
+use std::str::FromStr;
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Eq, PartialEq, EnumString, Display, AsRefStr, IntoStaticStr, VariantNames)]
+#[strum(strip_prefix = "Key")]
+enum KeyCode {
+    KeyEscape,
+    KeyEnter,
+    Space,
+    #[strum(serialize = "KeyTab")]
+    KeyTab,
+}
+
+#[test]
+fn strip_prefix() {
+    assert_eq!("Escape", KeyCode::KeyEscape.to_string());
+    assert_eq!("Enter", KeyCode::KeyEnter.as_ref());
+    assert_eq!("Space", <&'static str>::from(KeyCode::Space));
+    assert_eq!("KeyTab", KeyCode::KeyTab.to_string());
+    assert_eq!(KeyCode::KeyEscape, KeyCode::from_str("Escape").unwrap());
+    assert!(KeyCode::from_str("KeyEscape").is_err());
+    assert_eq!(["Escape", "Enter", "Space", "KeyTab"], KeyCode::VARIANTS);
+}
+
+#[derive(Debug, Eq, PartialEq, EnumString, Display, VariantNames, EnumMessage)]
+#[strum(strip_suffix = "Value", serialize_all = "snake_case")]
+enum Color {
+    RedValue,
+    DarkGreenValue,
+    Blue,
+}
+
+#[test]
+fn strip_suffix() {
+    assert_eq!("red", Color::RedValue.to_string());
+    assert_eq!("dark_green", Color::DarkGreenValue.to_string());
+    assert_eq!("blue", Color::Blue.to_string());
+    assert_eq!(
+        Color::DarkGreenValue,
+        Color::from_str("dark_green").unwrap()
+    );
+    assert_eq!(["red", "dark_green", "blue"], Color::VARIANTS);
+    assert_eq!(["dark_green"], Color::DarkGreenValue.get_serializations());
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Eq, PartialEq, EnumString, Display)]
+#[strum(
+    strip_prefix = "Color",
+    strip_suffix = "Value",
+    serialize_all = "kebab-case",
+    prefix = "color/"
+)]
+enum PrefixedColor {
+    ColorRedValue,
+    ColorLightBlueValue,
+}
+
+#[test]
+fn strip_prefix_and_suffix() {
+    assert_eq!("color/red", PrefixedColor::ColorRedValue.to_string());
+    assert_eq!(
+        "color/light-blue",
+        PrefixedColor::ColorLightBlueValue.to_string()
+    );
+    assert_eq!(
+        PrefixedColor::ColorLightBlueValue,
+        PrefixedColor::from_str("light-blue").unwrap()
+    );
+}
@@ -0,0 +1,95 @@
+use gratte::testing::{
+    assert_count_consistent, assert_display_roundtrip, assert_names_consistent,
+    assert_repr_roundtrip,
+};
+use gratte::{
+    AsRefStr, Display, EnumCount, EnumIter, EnumString, FromRepr, IntoStaticStr, VariantNames,
+};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    AsRefStr,
+    Display,
+    EnumCount,
+    EnumIter,
+    EnumString,
+    FromRepr,
+    VariantNames,
+)]
+#[strum(serialize_all = "kebab-case")]
+#[repr(u8)]
+enum Color {
+    Red = 1,
+    #[strum(to_string = "green", serialize = "lime")]
+    Green,
+    DarkBlue = 10,
+}
+
+#[test]
+fn consistent_enum() {
+    assert_display_roundtrip::<Color>();
+    assert_names_consistent::<Color>();
+    assert_count_consistent::<Color>();
+    assert_repr_roundtrip::<Color>();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Display, EnumIter, EnumString)]
+enum Ambiguous {
+    #[strum(serialize = "a", ascii_case_insensitive)]
+    First,
+    #[strum(serialize = "A")]
+    Second,
+}
+
+#[test]
+#[should_panic(expected = "variant `Second` is displayed as \"A\", which is parsed as `First`")]
+fn display_roundtrip_mismatch() {
+    assert_display_roundtrip::<Ambiguous>();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, AsRefStr, EnumCount, EnumIter, VariantNames)]
+enum PartiallySkipped {
+    Shown,
+    #[strum(skip(names))]
+    Hidden,
+}
+
+#[test]
+#[should_panic(
+    expected = "variant `Hidden` is named \"Hidden\", but `VARIANTS` has only 1 entries"
+)]
+fn names_mismatch() {
+    assert_names_consistent::<PartiallySkipped>();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, IntoStaticStr, EnumCount, EnumIter)]
+enum CountSkipped {
+    Shown,
+    #[strum(skip(iter))]
+    Hidden,
+}
+
+#[test]
+fn count_skipped() {
+    assert_eq!("Hidden", <&'static str>::from(CountSkipped::Hidden));
+    assert_count_consistent::<CountSkipped>();
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter, FromRepr)]
+enum ReprSkipped {
+    Shown,
+    #[strum(skip(repr))]
+    Hidden,
+}
+
+#[test]
+#[should_panic(expected = "variant `Hidden` has repr 1, which is not converted to any variant")]
+fn repr_roundtrip_mismatch() {
+    assert_eq!(Some(ReprSkipped::Shown), ReprSkipped::from_repr(0));
+    assert_repr_roundtrip::<ReprSkipped>();
+}
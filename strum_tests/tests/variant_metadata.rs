@@ -0,0 +1,73 @@
+use gratte::{IntoStaticStr, VariantIterator, VariantMetadata};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, PartialEq)]
+struct NotDefault(u32);
+
+#[derive(Debug, PartialEq, IntoStaticStr, VariantIterator, VariantMetadata)]
+#[strum(serialize_all = "snake_case", prefix = "event_")]
+enum Event {
+    Start,
+    Data(NotDefault),
+    #[strum(to_string = "finish")]
+    Stop,
+    #[strum(skip(iter, names))]
+    Paused,
+    #[strum(disabled)]
+    Unknown {
+        code: u32,
+    },
+}
+
+#[test]
+fn variant_iterator() {
+    let events: Vec<_> = <Event as VariantIterator>::iter().collect();
+    assert_eq!(vec![Event::Start, Event::Stop], events);
+    assert_eq!(2, <Event as VariantIterator>::iter().len());
+}
+
+#[derive(Debug, PartialEq, VariantIterator)]
+enum Level {
+    Low,
+    #[allow(dead_code)]
+    Custom(u8),
+    High,
+}
+
+#[test]
+fn variant_iterator_default_fields() {
+    let levels: Vec<_> = <Level as VariantIterator>::iter().collect();
+    assert_eq!(vec![Level::Low, Level::High], levels);
+}
+
+#[test]
+fn variant_count_and_names() {
+    assert_eq!(3, Event::VARIANT_COUNT);
+    assert_eq!(
+        ["event_start", "event_data", "event_finish"],
+        Event::VARIANT_NAMES
+    );
+}
+
+#[test]
+fn variant_name() {
+    assert_eq!("event_start", Event::Start.variant_name());
+    assert_eq!("event_data", Event::Data(NotDefault(1)).variant_name());
+    assert_eq!("event_finish", Event::Stop.variant_name());
+    assert_eq!("event_paused", Event::Paused.variant_name());
+    assert_eq!("event_unknown", Event::Unknown { code: 1 }.variant_name());
+    assert_eq!(
+        <&'static str>::from(Event::Stop),
+        Event::Stop.variant_name()
+    );
+}
+
+#[derive(VariantIterator, VariantMetadata)]
+enum Empty {}
+
+#[test]
+fn empty() {
+    assert_eq!(0, <Empty as VariantIterator>::iter().count());
+    assert_eq!(0, Empty::VARIANT_COUNT);
+}
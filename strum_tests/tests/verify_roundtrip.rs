@@ -0,0 +1,42 @@
+use gratte::{Display, EnumString};
+
+mod core {} // ensure macros call `::core`
+
+#[derive(Debug, Eq, PartialEq, Clone, Display, EnumString)]
+#[strum(verify_roundtrip, serialize_all = "snake_case")]
+enum Command {
+    #[strum(to_string = "go", serialize = "move")]
+    Go,
+    #[strum(serialize = "halt", serialize = "stop")]
+    Stop,
+    LookAround,
+    #[allow(dead_code)]
+    #[strum(disabled)]
+    Hidden,
+    #[strum(default)]
+    Other(String),
+}
+
+#[test]
+fn roundtrip() {
+    for command in [Command::Go, Command::Stop, Command::LookAround] {
+        assert_eq!(Ok(command.clone()), command.to_string().parse::<Command>());
+    }
+    assert_eq!("look_around", Command::LookAround.to_string());
+}
+
+#[derive(Debug, Eq, PartialEq, Display, EnumString)]
+#[strum(verify_roundtrip, ascii_case_insensitive, serialize_all = "UPPERCASE")]
+enum Level {
+    Low,
+    #[strum(serialize = "mid")]
+    Medium,
+    High,
+}
+
+#[test]
+fn roundtrip_case_insensitive() {
+    assert_eq!(Ok(Level::Low), Level::Low.to_string().parse());
+    assert_eq!(Ok(Level::Medium), Level::Medium.to_string().parse());
+    assert_eq!(Ok(Level::High), "high".parse());
+}